@@ -1,27 +1,216 @@
 /* tslint:disable */
 /* eslint-disable */
 /**
+* @param {number} radius
+* @param {string} shape
+* @param {number} intensity
+*/
+export function set_brush(radius: number, shape: string, intensity: number): void;
+/**
+* @param {string} source
+* @returns {string}
+*/
+export function custom_shader_source(source: string): string;
+/**
+* @returns {number}
+*/
+export function density(): number;
+/**
+* @returns {number}
+*/
+export function seed(): number;
+/**
 */
 export function start(): void;
 /**
-* @param {string} shader_source
-* @param {number} scale
+* @param {number} width
+* @param {number} height
 * @param {number} states
-* @param {number} kernel
+* @param {string} rule
+* @param {number | undefined} [seed]
+* @param {number | undefined} [density]
+*/
+export function reset_simulation(width: number, height: number, states: number, rule: string, seed?: number, density?: number): void;
+/**
+*/
+export function resume(): void;
+/**
+* @param {string} json
+* @param {number | undefined} [x]
+* @param {number | undefined} [y]
+* @returns {string}
+*/
+export function load_lenia_animal(json: string, x?: number, y?: number): string;
+/**
+* @param {number} generations_per_second
+*/
+export function set_generations_per_second(generations_per_second: number): void;
+/**
+* @returns {boolean}
+*/
+export function is_binary(): boolean;
+/**
+* @param {string} source
+*/
+export function set_custom_shader(source: string): void;
+/**
+*/
+export function pause(): void;
+/**
+* @param {string} rule
+* @returns {string}
+*/
+export function shader_source(rule: string): string;
+/**
+* @param {string} stops
+*/
+export function set_palette_stops(stops: string): void;
+/**
+* @param {number} width
+* @param {number} height
+*/
+export function resize_world(width: number, height: number): void;
+/**
+* @returns {boolean}
+*/
+export function is_paused(): boolean;
+/**
+*/
+export function reset_camera(): void;
+/**
+* @param {string | undefined} [rule]
+* @returns {string}
+*/
+export function export_rle(rule?: string): string;
+/**
+* @returns {string}
+*/
+export function state_precision(): string;
+/**
+* @param {string} rle
+* @returns {string | undefined}
+*/
+export function select_stamp_rle(rle: string): string | undefined;
+/**
+* @returns {Float32Array}
+*/
+export function export_cells(): Float32Array;
+/**
+* @param {string} mode
+*/
+export function set_resize_mode(mode: string): void;
+/**
+* @param {number} radius
+* @param {Float32Array} peaks
+* @param {string} core
+* @param {number} mu
+* @param {number} sigma
+* @param {number} time_step
+* @param {string | undefined} [growth]
+*/
+export function set_lenia_kernel(radius: number, peaks: Float32Array, core: string, mu: number, sigma: number, time_step: number, growth?: string): void;
+/**
+* @param {string} name
+*/
+export function set_palette(name: string): void;
+/**
+* @param {string} name
+* @param {number} value
+*/
+export function set_param(name: string, value: number): void;
+/**
+* @param {string | undefined} [name]
+*/
+export function select_stamp(name?: string): void;
+/**
+* @returns {any}
+*/
+export function presets(): any;
+/**
+* @param {number} zoom
+* @param {number} x
+* @param {number} y
+*/
+export function set_camera(zoom: number, x: number, y: number): void;
+/**
+* @param {number} n
+*/
+export function step(n: number): void;
+/**
+* @param {number} quarter_turns
+* @param {boolean} mirrored
+*/
+export function set_stamp_orientation(quarter_turns: number, mirrored: boolean): void;
+/**
+* @returns {number}
+*/
+export function generations_per_second(): number;
+/**
+* @param {string} name
+* @param {number | undefined} [size]
+*/
+export function set_initial_condition(name: string, size?: number): void;
+/**
+* @returns {Uint8Array}
+*/
+export function export_png(): Uint8Array;
+/**
+* @param {string} rle
+* @param {number | undefined} [x]
+* @param {number | undefined} [y]
+* @returns {string | undefined}
+*/
+export function load_rle(rle: string, x?: number, y?: number): string | undefined;
+/**
+* @returns {Uint8Array}
 */
-export function reset_simulation(shader_source: string, scale: number, states: number, kernel: number): void;
+export function export_bytes(): Uint8Array;
 
 export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
 
 export interface InitOutput {
   readonly memory: WebAssembly.Memory;
+  readonly custom_shader_source: (a: number, b: number, c: number) => void;
+  readonly density: (a: number) => void;
+  readonly export_bytes: (a: number) => void;
+  readonly export_cells: (a: number) => void;
+  readonly export_png: (a: number) => void;
+  readonly export_rle: (a: number, b: number, c: number) => void;
+  readonly is_binary: (a: number) => void;
+  readonly is_paused: () => number;
+  readonly load_lenia_animal: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+  readonly load_rle: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+  readonly presets: (a: number) => void;
+  readonly reset_camera: (a: number) => void;
+  readonly reset_simulation: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => void;
+  readonly resize_world: (a: number, b: number, c: number) => void;
+  readonly seed: (a: number) => void;
+  readonly select_stamp: (a: number, b: number, c: number) => void;
+  readonly select_stamp_rle: (a: number, b: number, c: number) => void;
+  readonly set_brush: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly set_camera: (a: number, b: number, c: number, d: number) => void;
+  readonly set_custom_shader: (a: number, b: number, c: number) => void;
+  readonly set_generations_per_second: (a: number, b: number) => void;
+  readonly set_initial_condition: (a: number, b: number, c: number, d: number, e: number) => void;
+  readonly set_lenia_kernel: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number) => void;
+  readonly set_palette: (a: number, b: number, c: number) => void;
+  readonly set_palette_stops: (a: number, b: number, c: number) => void;
+  readonly set_param: (a: number, b: number, c: number, d: number) => void;
+  readonly set_resize_mode: (a: number, b: number, c: number) => void;
+  readonly set_stamp_orientation: (a: number, b: number) => void;
+  readonly shader_source: (a: number, b: number, c: number) => void;
   readonly start: () => void;
-  readonly reset_simulation: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+  readonly state_precision: (a: number) => void;
+  readonly step: (a: number, b: number) => void;
+  readonly generations_per_second: () => number;
+  readonly pause: () => void;
+  readonly resume: () => void;
   readonly __wbindgen_malloc: (a: number, b: number) => number;
   readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
   readonly __wbindgen_export_2: WebAssembly.Table;
-  readonly _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h53515e5c752157f5: (a: number, b: number) => void;
-  readonly _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h07f2874de9708c52: (a: number, b: number, c: number) => void;
+  readonly _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h5b63251d67c1f24d: (a: number, b: number, c: number) => void;
+  readonly _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h4f82e256eebb4f37: (a: number, b: number) => void;
+  readonly _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__ha331562f2054315e: (a: number, b: number, c: number) => void;
   readonly __wbindgen_add_to_stack_pointer: (a: number) => number;
   readonly __wbindgen_free: (a: number, b: number, c: number) => void;
   readonly __wbindgen_exn_store: (a: number) => void;
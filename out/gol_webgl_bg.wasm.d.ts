@@ -1,13 +1,47 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
+export function custom_shader_source(a: number, b: number, c: number): void;
+export function density(a: number): void;
+export function export_bytes(a: number): void;
+export function export_cells(a: number): void;
+export function export_png(a: number): void;
+export function export_rle(a: number, b: number, c: number): void;
+export function is_binary(a: number): void;
+export function is_paused(): number;
+export function load_lenia_animal(a: number, b: number, c: number, d: number, e: number, f: number, g: number): void;
+export function load_rle(a: number, b: number, c: number, d: number, e: number, f: number, g: number): void;
+export function presets(a: number): void;
+export function reset_camera(a: number): void;
+export function reset_simulation(a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number): void;
+export function resize_world(a: number, b: number, c: number): void;
+export function seed(a: number): void;
+export function select_stamp(a: number, b: number, c: number): void;
+export function select_stamp_rle(a: number, b: number, c: number): void;
+export function set_brush(a: number, b: number, c: number, d: number, e: number): void;
+export function set_camera(a: number, b: number, c: number, d: number): void;
+export function set_custom_shader(a: number, b: number, c: number): void;
+export function set_generations_per_second(a: number, b: number): void;
+export function set_initial_condition(a: number, b: number, c: number, d: number, e: number): void;
+export function set_lenia_kernel(a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number): void;
+export function set_palette(a: number, b: number, c: number): void;
+export function set_palette_stops(a: number, b: number, c: number): void;
+export function set_param(a: number, b: number, c: number, d: number): void;
+export function set_resize_mode(a: number, b: number, c: number): void;
+export function set_stamp_orientation(a: number, b: number): void;
+export function shader_source(a: number, b: number, c: number): void;
 export function start(): void;
-export function reset_simulation(a: number, b: number, c: number, d: number, e: number, f: number): void;
+export function state_precision(a: number): void;
+export function step(a: number, b: number): void;
+export function generations_per_second(): number;
+export function pause(): void;
+export function resume(): void;
 export function __wbindgen_malloc(a: number, b: number): number;
 export function __wbindgen_realloc(a: number, b: number, c: number, d: number): number;
 export const __wbindgen_export_2: WebAssembly.Table;
-export function _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h53515e5c752157f5(a: number, b: number): void;
-export function _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h07f2874de9708c52(a: number, b: number, c: number): void;
+export function _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h5b63251d67c1f24d(a: number, b: number, c: number): void;
+export function _dyn_core__ops__function__FnMut_____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__h4f82e256eebb4f37(a: number, b: number): void;
+export function _dyn_core__ops__function__FnMut__A____Output___R_as_wasm_bindgen__closure__WasmClosure___describe__invoke__ha331562f2054315e(a: number, b: number, c: number): void;
 export function __wbindgen_add_to_stack_pointer(a: number): number;
 export function __wbindgen_free(a: number, b: number, c: number): void;
 export function __wbindgen_exn_store(a: number): void;
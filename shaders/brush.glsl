@@ -0,0 +1,52 @@
+#version 300 es
+precision highp float;
+
+// applies up to 16 brush stamps to the state texture in one pass
+uniform sampler2D u_current_state;
+uniform vec2 u_resolution;
+
+// x and y in cells, z is 1.0 to erase and 0.0 to paint
+uniform vec3 u_stamps[16];
+uniform int u_stamp_count;
+
+uniform float u_radius;
+// 0: circle, 1: square, 2: soft circle
+uniform int u_shape;
+uniform float u_intensity;
+// binary rules only know dead and alive, so any touched cell is set outright
+uniform int u_binary;
+
+out vec4 outColor;
+
+float weight(vec2 d) {
+    if (u_shape == 1) {
+        return float(max(abs(d.x), abs(d.y)) <= u_radius);
+    }
+    float r = length(d);
+    if (u_shape == 2) {
+        return 1.0 - smoothstep(0.0, u_radius, r);
+    }
+    return float(r <= u_radius);
+}
+
+void main() {
+    float state = texture(u_current_state, gl_FragCoord.xy / u_resolution).r;
+
+    for (int i = 0; i < u_stamp_count; i++) {
+        vec2 d = gl_FragCoord.xy - u_stamps[i].xy;
+        // the world is a torus, take the nearest copy of the stamp
+        d -= u_resolution * round(d / u_resolution);
+        float w = weight(d) * u_intensity;
+        if (w <= 0.0) {
+            continue;
+        }
+        bool erase = u_stamps[i].z > 0.5;
+        if (u_binary == 1) {
+            state = erase ? 0.0 : 1.0;
+        } else {
+            state = clamp(state + (erase ? -w : w), 0.0, 1.0);
+        }
+    }
+
+    outColor = vec4(vec3(state), 1.0);
+}
@@ -0,0 +1,11 @@
+// state of the previous generation, one cell per texel, and the world size in cells
+uniform sampler2D u_current_state;
+uniform vec2 u_resolution;
+
+out vec4 outColor;
+
+// state of the cell (dx, dy) away from this one; the world is a torus
+float neighbour(int dx, int dy) {
+    vec2 texCoord = (gl_FragCoord.xy + vec2(float(dx), float(dy))) / u_resolution;
+    return texture(u_current_state, fract(texCoord)).r;
+}
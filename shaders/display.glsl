@@ -0,0 +1,43 @@
+#version 300 es
+precision highp float;
+
+// draws the state texture to the canvas; the rule shaders only ever write state values
+uniform sampler2D u_state;
+// canvas size in pixels and world size in cells; the world is scaled to fit the canvas
+uniform vec2 u_resolution;
+uniform vec2 u_world;
+uniform vec2 u_mouse;
+// camera: magnification and the point of the world, in texture coordinates, at the centre
+uniform float u_zoom;
+uniform vec2 u_center;
+// 0: look the state up in u_palette, 1: shade live cells by their position
+uniform int u_colour_map;
+// 256x1 colour table, see palette.rs
+uniform sampler2D u_palette;
+
+out vec4 outColor;
+
+vec3 colour_map(float state, vec2 texCoord) {
+    if (u_colour_map == 1) {
+        // the old Game of Life look
+        return state * vec3(texCoord, 1.0);
+    }
+    int index = int(clamp(state, 0.0, 1.0) * 255.0 + 0.5);
+    return texelFetch(u_palette, ivec2(index, 0), 0).rgb;
+}
+
+void main() {
+    float fit = min(u_resolution.x / u_world.x, u_resolution.y / u_world.y);
+    vec2 offset = (gl_FragCoord.xy - 0.5 * u_resolution) / (fit * u_zoom * u_world);
+    // the world is a torus: the view wraps around, but shows at most one copy of it
+    vec4 colour = vec4(0.0, 0.0, 0.0, 1.0);
+    if (abs(offset.x) <= 0.5 && abs(offset.y) <= 0.5) {
+        vec2 texCoord = fract(u_center + offset);
+        float state = texture(u_state, texCoord).r;
+        colour = vec4(colour_map(state, texCoord), 1.0);
+    }
+
+    // cursor
+    float dist = distance(gl_FragCoord.xy, u_mouse);
+    outColor = mix(colour, vec4(1.0, 0.0, 0.0, 1.0), smoothstep(5.0, 0.0, dist));
+}
@@ -1,14 +0,0 @@
-const int R = 5;
-const int kernel_size = 11;
-const int kernel_length = 121;
-
-const float T = 10.0;
-
-const float m = 0.15;
-const float s = 0.015;
-
-
-const float kernel[kernel_length] = float[kernel_length](
-    // Kernel 2: circle
-    0.0,0.0,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.0,0.0,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.0,0.0,0.0,0.0,0.012500000000000000693889390391,0.012500000000000000693889390391,0.012500000000000000693889390391,0.0,0.0,0.0,0.0
-);
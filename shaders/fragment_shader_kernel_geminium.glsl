@@ -1,14 +0,0 @@
-const int R = 32;
-const int kernel_size = 64;
-const int kernel_length = 64*64;
-
-const float T = 5.0;
-
-const float m = 0.26;
-const float s = 0.036;
-
-
-const float kernel[kernel_length] = float[kernel_length](
-    // Kernel 5: Geminium
-    0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.006929064842820123991395941943,0.015710328739062143915683478212,0.028289150249923390029183067895,0.041916553600206796215132953876,0.052486828606181840095601387475,0.056466878411227702794228378025,0.052486828606181840095601387475,0.041916553600206796215132953876,0.028289150249923390029183067895,0.015710328739062143915683478212,0.006929064842820123991395941943,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.003866566714704468884361565628,0.015710328739062143915683478212,0.045221414044208166349569921749,0.097909965236922871789637667916,0.168662236819697219925018316644,0.243056739497610502231950135865,0.305580370448915117354005133166,0.345973370821185677304043792901,0.359784807327497291318252337078,0.345973370821185677304043792901,0.305580370448915117354005133166,0.243056739497610502231950135865,0.168662236819697219925018316644,0.097909965236922871789637667916,0.045221414044208166349569921749,0.015710328739062143915683478212,0.003866566714704468884361565628,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.014393607781830908276532454693,0.056466878411227702794228378025,0.150524192998700373502529714642,0.292543292568001878084515965384,0.444255814514749713950436671439,0.562947095984884793296032512444,0.631639347673919826497979101987,0.659784050050305825863006248255,0.666543693958222438489258365735,0.667000000000000037303493627405,0.666543693958222438489258365735,0.659784050050305825863006248255,0.631639347673919826497979101987,0.562947095984884793296032512444,0.444255814514749713950436671439,0.292543292568001878084515965384,0.150524192998700373502529714642,0.056466878411227702794228378025,0.014393607781830908276532454693,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.005195210964754794734365983544,0.033198055150686286984829109770,0.125941874360487621187232321063,0.305580370448915117354005133166,0.512856975326175246188142864412,0.645287014389146040826972239302,0.659655326078040449289119351306,0.591938786967272201344769655407,0.500459054191348395868033094303,0.423482195985292575457492603164,0.375687003729732682533182241968,0.359784807327497291318252337078,0.375687003729732682533182241968,0.423482195985292575457492603164,0.500459054191348395868033094303,0.591938786967272201344769655407,0.659655326078040449289119351306,0.645287014389146040826972239302,0.512856975326175246188142864412,0.305580370448915117354005133166,0.125941874360487621187232321063,0.033198055150686286984829109770,0.005195210964754794734365983544,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.007614557614842068847260669884,0.052486828606181840095601387475,0.198503129203038780614676284131,0.444255814514749713950436671439,0.638842372821403325922062776954,0.644612387177403323157420800271,0.500459054191348395868033094303,0.328301036417308733650344265698,0.199428103502484715958686933845,0.122145259306724929260212775262,0.081195504699406062520949944883,0.062027044125987529366028638833,0.056466878411227369727320990478,0.062027044125987529366028638833,0.081195504699406062520949944883,0.122145259306724929260212775262,0.199428103502484715958686933845,0.328301036417308733650344265698,0.500459054191348395868033094303,0.644612387177403323157420800271,0.638842372821403325922062776954,0.444255814514749713950436671439,0.198503129203038780614676284131,0.052486828606181840095601387475,0.007614557614842068847260669884,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.007614557614842068847260669884,0.060691515251315872325754696703,0.243056739497610502231950135865,0.525906647878404465146218171867,0.667000000000000037303493627405,0.542784127274306471555576081300,0.312804993994233448972863698145,0.141852466032250268401782022920,0.056466878411227369727320990478,0.022004757948541068396375308680,0.009308056895955259951724869438,0.004680371971332184834191370015,0.003004828567809282417744309868,0.002578568733028360499687225982,0.003004828567809282417744309868,0.004680371971332184834191370015,0.009308056895955259951724869438,0.022004757948541068396375308680,0.056466878411227369727320990478,0.141852466032250268401782022920,0.312804993994233448972863698145,0.542784127274306471555576081300,0.667000000000000037303493627405,0.525906647878404465146218171867,0.243056739497610502231950135865,0.060691515251315872325754696703,0.007614557614842068847260669884,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.005195210964754794734365983544,0.052486828606181840095601387475,0.243056739497610502231950135865,0.550994920849954827879457752715,0.659655326078040449289119351306,0.454929834928519405412572496061,0.199428103502484715958686933845,0.062027044125987529366028638833,0.015435852309432439646852408544,0.003492280597131325672877188993,0.010936165957362780493111742430,0.028417354801301336619800963490,0.053157552993335681346120935586,0.075591725639036669304182680662,0.084657988622529781186365482881,0.075591725639036669304182680662,0.053157552993335681346120935586,0.028417354801301336619800963490,0.010936165957362780493111742430,0.003492280597131325672877188993,0.015435852309432439646852408544,0.062027044125987529366028638833,0.199428103502484715958686933845,0.454929834928519405412572496061,0.659655326078040449289119351306,0.550994920849954827879457752715,0.243056739497610502231950135865,0.052486828606181840095601387475,0.005195210964754794734365983544,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.033198055150686286984829109770,0.198503129203038780614676284131,0.525906647878404465146218171867,0.659655326078040449289119351306,0.423482195985292575457492603164,0.152427969332705803839900227103,0.034195114751501783090237296392,0.005397407427922606970582819486,0.012601889579890421938523914491,0.053157552993335681346120935586,0.144480717365489341741380258100,0.277885821767910656809874581086,0.412307389132855084401541034822,0.506417255837276814034453309432,0.539407507237626315799161602627,0.506417255837276814034453309432,0.412307389132855084401541034822,0.277885821767910656809874581086,0.144480717365489341741380258100,0.053157552993335681346120935586,0.012601889579890421938523914491,0.005397407427922606970582819486,0.034195114751501783090237296392,0.152427969332705803839900227103,0.423482195985292575457492603164,0.659655326078040449289119351306,0.525906647878404465146218171867,0.198503129203038780614676284131,0.033198055150686286984829109770,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.014393607781830908276532454693,0.125941874360487621187232321063,0.444255814514749713950436671439,0.667000000000000037303493627405,0.454929834928519405412572496061,0.152427969332705803839900227103,0.027554381265135988871017858060,0.003004828567809282417744309868,0.028417354801301336619800963490,0.130395947587439803294628859476,0.354797416542333876599002451258,0.640958955183616896356113556976,0.863898307628227279941768301796,0.970685317889799503809911129792,0.998108264722803006918638857314,1.000000000000000000000000000000,0.998108264722803006918638857314,0.970685317889799503809911129792,0.863898307628227279941768301796,0.640958955183616896356113556976,0.354797416542333876599002451258,0.130395947587439803294628859476,0.028417354801301336619800963490,0.003004828567809282417744309868,0.027554381265135988871017858060,0.152427969332705803839900227103,0.454929834928519405412572496061,0.667000000000000037303493627405,0.444255814514749713950436671439,0.125941874360487621187232321063,0.014393607781830908276532454693,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.003866566714704468884361565628,0.056466878411227702794228378025,0.305580370448915117354005133166,0.638842372821403325922062776954,0.542784127274306471555576081300,0.199428103502484715958686933845,0.034195114751501783090237296392,0.003004828567809282417744309868,0.036718540231265403561700111368,0.193911159186147485922191435748,0.539407507237626315799161602627,0.890244596459464188065169309994,0.998084786973467807946747143433,0.879542452480196468300732703938,0.704167388165951257938957041915,0.581110040064810595694666517375,0.539407507237626315799161602627,0.581110040064810595694666517375,0.704167388165951257938957041915,0.879542452480196468300732703938,0.998084786973467807946747143433,0.890244596459464188065169309994,0.539407507237626315799161602627,0.193911159186147485922191435748,0.036718540231265403561700111368,0.003004828567809282417744309868,0.034195114751501783090237296392,0.199428103502484715958686933845,0.542784127274306471555576081300,0.638842372821403325922062776954,0.305580370448915117354005133166,0.056466878411227702794228378025,0.003866566714704468884361565628,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.015710328739062143915683478212,0.150524192998700373502529714642,0.512856975326175246188142864412,0.644612387177403323157420800271,0.312804993994233448972863698145,0.062027044125987529366028638833,0.005397407427922606970582819486,0.028417354801301336619800963490,0.193911159186147485922191435748,0.606871033488146083278991227417,0.970685317889799503809911129792,0.906993051696591945365355513786,0.581110040064810595694666517375,0.306429379524223388653325628184,0.161066250987676767847389669441,0.100582459131878729219344847934,0.084657988622530017108758215727,0.100582459131878729219344847934,0.161066250987676767847389669441,0.306429379524223388653325628184,0.581110040064810595694666517375,0.906993051696591945365355513786,0.970685317889799503809911129792,0.606871033488146083278991227417,0.193911159186147485922191435748,0.028417354801301336619800963490,0.005397407427922606970582819486,0.062027044125987529366028638833,0.312804993994233448972863698145,0.644612387177403323157420800271,0.512856975326175246188142864412,0.150524192998700373502529714642,0.015710328739062143915683478212,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.045221414044208166349569921749,0.292543292568001878084515965384,0.645287014389146040826972239302,0.500459054191348395868033094303,0.141852466032250268401782022920,0.015435852309432439646852408544,0.012601889579890421938523914491,0.130395947587439803294628859476,0.539407507237626315799161602627,0.970685317889799503809911129792,0.849132289966892828125821779395,0.417375478972470237426506400880,0.138705297961296242226225672312,0.039078668333498808862280782250,0.012052042491249399455699453654,0.005230461121774356708646891434,0.003865920139472804085978285826,0.005230461121774356708646891434,0.012052042491249399455699453654,0.039078668333498808862280782250,0.138705297961296242226225672312,0.417375478972470237426506400880,0.849132289966892828125821779395,0.970685317889799503809911129792,0.539407507237626315799161602627,0.130395947587439803294628859476,0.012601889579890421938523914491,0.015435852309432439646852408544,0.141852466032250268401782022920,0.500459054191348395868033094303,0.645287014389146040826972239302,0.292543292568001878084515965384,0.045221414044208166349569921749,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.006929064842820123991395941943,0.097909965236922871789637667916,0.444255814514749713950436671439,0.659655326078040449289119351306,0.328301036417308733650344265698,0.056466878411227369727320990478,0.003492280597131325672877188993,0.053157552993335681346120935586,0.354797416542333876599002451258,0.890244596459464188065169309994,0.906993051696591945365355513786,0.417375478972470237426506400880,0.100582459131878729219344847934,0.015563651509072821618739901339,0.003552019627716161177810594296,0.014922539919884321782395097955,0.032947892045702489860481421147,0.042328994311264932226546164884,0.032947892045702489860481421147,0.014922539919884321782395097955,0.003552019627716161177810594296,0.015563651509072821618739901339,0.100582459131878729219344847934,0.417375478972470237426506400880,0.906993051696591945365355513786,0.890244596459464188065169309994,0.354797416542333876599002451258,0.053157552993335681346120935586,0.003492280597131325672877188993,0.056466878411227369727320990478,0.328301036417308733650344265698,0.659655326078040449289119351306,0.444255814514749713950436671439,0.097909965236922871789637667916,0.006929064842820123991395941943,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.015710328739062143915683478212,0.168662236819697219925018316644,0.562947095984884793296032512444,0.591938786967272201344769655407,0.199428103502484715958686933845,0.022004757948541068396375308680,0.010936165957362780493111742430,0.144480717365489341741380258100,0.640958955183616896356113556976,0.998084786973467807946747143433,0.581110040064810595694666517375,0.138705297961296242226225672312,0.015563651509072821618739901339,0.006406276149929151370043811653,0.042328994311264932226546164884,0.131215371506433697845750430133,0.229519262567518395057319935404,0.269703753618813379944185726345,0.229519262567518395057319935404,0.131215371506433697845750430133,0.042328994311264932226546164884,0.006406276149929151370043811653,0.015563651509072821618739901339,0.138705297961296242226225672312,0.581110040064810595694666517375,0.998084786973467807946747143433,0.640958955183616896356113556976,0.144480717365489341741380258100,0.010936165957362780493111742430,0.022004757948541068396375308680,0.199428103502484715958686933845,0.591938786967272201344769655407,0.562947095984884793296032512444,0.168662236819697219925018316644,0.015710328739062143915683478212,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.028289150249923390029183067895,0.243056739497610502231950135865,0.631639347673919826497979101987,0.500459054191348395868033094303,0.122145259306724929260212775262,0.009308056895955259951724869438,0.028417354801301336619800963490,0.277885821767910656809874581086,0.863898307628227279941768301796,0.879542452480196468300732703938,0.306429379524223388653325628184,0.039078668333498808862280782250,0.003552019627716161177810594296,0.042328994311264932226546164884,0.192756061250082849634068793421,0.398718158520917076437228843133,0.491937914652442020990719129259,0.500000000000000000000000000000,0.491937914652442020990719129259,0.398718158520917076437228843133,0.192756061250082849634068793421,0.042328994311264932226546164884,0.003552019627716161177810594296,0.039078668333498808862280782250,0.306429379524223388653325628184,0.879542452480196468300732703938,0.863898307628227279941768301796,0.277885821767910656809874581086,0.028417354801301336619800963490,0.009308056895955259951724869438,0.122145259306724929260212775262,0.500459054191348395868033094303,0.631639347673919826497979101987,0.243056739497610502231950135865,0.028289150249923390029183067895,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.041916553600206796215132953876,0.305580370448915117354005133166,0.659784050050305825863006248255,0.423482195985292575457492603164,0.081195504699406062520949944883,0.004680371971332184834191370015,0.053157552993335681346120935586,0.412307389132855084401541034822,0.970685317889799503809911129792,0.704167388165951257938957041915,0.161066250987676767847389669441,0.012052042491249399455699453654,0.014922539919884321782395097955,0.131215371506433697845750430133,0.398718158520917076437228843133,0.490996478419380932844973131068,0.348753733962036127635997218022,0.269703753618813213410732032571,0.348753733962036127635997218022,0.490996478419380932844973131068,0.398718158520917076437228843133,0.131215371506433697845750430133,0.014922539919884321782395097955,0.012052042491249399455699453654,0.161066250987676767847389669441,0.704167388165951257938957041915,0.970685317889799503809911129792,0.412307389132855084401541034822,0.053157552993335681346120935586,0.004680371971332184834191370015,0.081195504699406062520949944883,0.423482195985292575457492603164,0.659784050050305825863006248255,0.305580370448915117354005133166,0.041916553600206796215132953876,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.052486828606181840095601387475,0.345973370821185677304043792901,0.666543693958222438489258365735,0.375687003729732682533182241968,0.062027044125987529366028638833,0.003004828567809282417744309868,0.075591725639036669304182680662,0.506417255837276814034453309432,0.998108264722803006918638857314,0.581110040064810595694666517375,0.100582459131878729219344847934,0.005230461121774356708646891434,0.032947892045702489860481421147,0.229519262567518395057319935404,0.491937914652442020990719129259,0.348753733962036127635997218022,0.105880664193931162131434575713,0.042328994311264932226546164884,0.105880664193931162131434575713,0.348753733962036127635997218022,0.491937914652442020990719129259,0.229519262567518395057319935404,0.032947892045702489860481421147,0.005230461121774356708646891434,0.100582459131878729219344847934,0.581110040064810595694666517375,0.998108264722803006918638857314,0.506417255837276814034453309432,0.075591725639036669304182680662,0.003004828567809282417744309868,0.062027044125987529366028638833,0.375687003729732682533182241968,0.666543693958222438489258365735,0.345973370821185677304043792901,0.052486828606181840095601387475,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.056466878411227702794228378025,0.359784807327497291318252337078,0.667000000000000037303493627405,0.359784807327497291318252337078,0.056466878411227369727320990478,0.002578568733028360499687225982,0.084657988622529781186365482881,0.539407507237626315799161602627,1.000000000000000000000000000000,0.539407507237626315799161602627,0.084657988622530017108758215727,0.003865920139472804085978285826,0.042328994311264932226546164884,0.269703753618813379944185726345,0.500000000000000000000000000000,0.269703753618813213410732032571,0.042328994311264932226546164884,0.001932960069736402042989142913,0.042328994311264932226546164884,0.269703753618813213410732032571,0.500000000000000000000000000000,0.269703753618813379944185726345,0.042328994311264932226546164884,0.003865920139472804085978285826,0.084657988622530017108758215727,0.539407507237626315799161602627,1.000000000000000000000000000000,0.539407507237626315799161602627,0.084657988622529781186365482881,0.002578568733028360499687225982,0.056466878411227369727320990478,0.359784807327497291318252337078,0.667000000000000037303493627405,0.359784807327497291318252337078,0.056466878411227702794228378025,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.052486828606181840095601387475,0.345973370821185677304043792901,0.666543693958222438489258365735,0.375687003729732682533182241968,0.062027044125987529366028638833,0.003004828567809282417744309868,0.075591725639036669304182680662,0.506417255837276814034453309432,0.998108264722803006918638857314,0.581110040064810595694666517375,0.100582459131878729219344847934,0.005230461121774356708646891434,0.032947892045702489860481421147,0.229519262567518395057319935404,0.491937914652442020990719129259,0.348753733962036127635997218022,0.105880664193931162131434575713,0.042328994311264932226546164884,0.105880664193931162131434575713,0.348753733962036127635997218022,0.491937914652442020990719129259,0.229519262567518395057319935404,0.032947892045702489860481421147,0.005230461121774356708646891434,0.100582459131878729219344847934,0.581110040064810595694666517375,0.998108264722803006918638857314,0.506417255837276814034453309432,0.075591725639036669304182680662,0.003004828567809282417744309868,0.062027044125987529366028638833,0.375687003729732682533182241968,0.666543693958222438489258365735,0.345973370821185677304043792901,0.052486828606181840095601387475,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.041916553600206796215132953876,0.305580370448915117354005133166,0.659784050050305825863006248255,0.423482195985292575457492603164,0.081195504699406062520949944883,0.004680371971332184834191370015,0.053157552993335681346120935586,0.412307389132855084401541034822,0.970685317889799503809911129792,0.704167388165951257938957041915,0.161066250987676767847389669441,0.012052042491249399455699453654,0.014922539919884321782395097955,0.131215371506433697845750430133,0.398718158520917076437228843133,0.490996478419380932844973131068,0.348753733962036127635997218022,0.269703753618813213410732032571,0.348753733962036127635997218022,0.490996478419380932844973131068,0.398718158520917076437228843133,0.131215371506433697845750430133,0.014922539919884321782395097955,0.012052042491249399455699453654,0.161066250987676767847389669441,0.704167388165951257938957041915,0.970685317889799503809911129792,0.412307389132855084401541034822,0.053157552993335681346120935586,0.004680371971332184834191370015,0.081195504699406062520949944883,0.423482195985292575457492603164,0.659784050050305825863006248255,0.305580370448915117354005133166,0.041916553600206796215132953876,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.028289150249923390029183067895,0.243056739497610502231950135865,0.631639347673919826497979101987,0.500459054191348395868033094303,0.122145259306724929260212775262,0.009308056895955259951724869438,0.028417354801301336619800963490,0.277885821767910656809874581086,0.863898307628227279941768301796,0.879542452480196468300732703938,0.306429379524223388653325628184,0.039078668333498808862280782250,0.003552019627716161177810594296,0.042328994311264932226546164884,0.192756061250082849634068793421,0.398718158520917076437228843133,0.491937914652442020990719129259,0.500000000000000000000000000000,0.491937914652442020990719129259,0.398718158520917076437228843133,0.192756061250082849634068793421,0.042328994311264932226546164884,0.003552019627716161177810594296,0.039078668333498808862280782250,0.306429379524223388653325628184,0.879542452480196468300732703938,0.863898307628227279941768301796,0.277885821767910656809874581086,0.028417354801301336619800963490,0.009308056895955259951724869438,0.122145259306724929260212775262,0.500459054191348395868033094303,0.631639347673919826497979101987,0.243056739497610502231950135865,0.028289150249923390029183067895,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.015710328739062143915683478212,0.168662236819697219925018316644,0.562947095984884793296032512444,0.591938786967272201344769655407,0.199428103502484715958686933845,0.022004757948541068396375308680,0.010936165957362780493111742430,0.144480717365489341741380258100,0.640958955183616896356113556976,0.998084786973467807946747143433,0.581110040064810595694666517375,0.138705297961296242226225672312,0.015563651509072821618739901339,0.006406276149929151370043811653,0.042328994311264932226546164884,0.131215371506433697845750430133,0.229519262567518395057319935404,0.269703753618813379944185726345,0.229519262567518395057319935404,0.131215371506433697845750430133,0.042328994311264932226546164884,0.006406276149929151370043811653,0.015563651509072821618739901339,0.138705297961296242226225672312,0.581110040064810595694666517375,0.998084786973467807946747143433,0.640958955183616896356113556976,0.144480717365489341741380258100,0.010936165957362780493111742430,0.022004757948541068396375308680,0.199428103502484715958686933845,0.591938786967272201344769655407,0.562947095984884793296032512444,0.168662236819697219925018316644,0.015710328739062143915683478212,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.006929064842820123991395941943,0.097909965236922871789637667916,0.444255814514749713950436671439,0.659655326078040449289119351306,0.328301036417308733650344265698,0.056466878411227369727320990478,0.003492280597131325672877188993,0.053157552993335681346120935586,0.354797416542333876599002451258,0.890244596459464188065169309994,0.906993051696591945365355513786,0.417375478972470237426506400880,0.100582459131878729219344847934,0.015563651509072821618739901339,0.003552019627716161177810594296,0.014922539919884321782395097955,0.032947892045702489860481421147,0.042328994311264932226546164884,0.032947892045702489860481421147,0.014922539919884321782395097955,0.003552019627716161177810594296,0.015563651509072821618739901339,0.100582459131878729219344847934,0.417375478972470237426506400880,0.906993051696591945365355513786,0.890244596459464188065169309994,0.354797416542333876599002451258,0.053157552993335681346120935586,0.003492280597131325672877188993,0.056466878411227369727320990478,0.328301036417308733650344265698,0.659655326078040449289119351306,0.444255814514749713950436671439,0.097909965236922871789637667916,0.006929064842820123991395941943,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.045221414044208166349569921749,0.292543292568001878084515965384,0.645287014389146040826972239302,0.500459054191348395868033094303,0.141852466032250268401782022920,0.015435852309432439646852408544,0.012601889579890421938523914491,0.130395947587439803294628859476,0.539407507237626315799161602627,0.970685317889799503809911129792,0.849132289966892828125821779395,0.417375478972470237426506400880,0.138705297961296242226225672312,0.039078668333498808862280782250,0.012052042491249399455699453654,0.005230461121774356708646891434,0.003865920139472804085978285826,0.005230461121774356708646891434,0.012052042491249399455699453654,0.039078668333498808862280782250,0.138705297961296242226225672312,0.417375478972470237426506400880,0.849132289966892828125821779395,0.970685317889799503809911129792,0.539407507237626315799161602627,0.130395947587439803294628859476,0.012601889579890421938523914491,0.015435852309432439646852408544,0.141852466032250268401782022920,0.500459054191348395868033094303,0.645287014389146040826972239302,0.292543292568001878084515965384,0.045221414044208166349569921749,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.015710328739062143915683478212,0.150524192998700373502529714642,0.512856975326175246188142864412,0.644612387177403323157420800271,0.312804993994233448972863698145,0.062027044125987529366028638833,0.005397407427922606970582819486,0.028417354801301336619800963490,0.193911159186147485922191435748,0.606871033488146083278991227417,0.970685317889799503809911129792,0.906993051696591945365355513786,0.581110040064810595694666517375,0.306429379524223388653325628184,0.161066250987676767847389669441,0.100582459131878729219344847934,0.084657988622530017108758215727,0.100582459131878729219344847934,0.161066250987676767847389669441,0.306429379524223388653325628184,0.581110040064810595694666517375,0.906993051696591945365355513786,0.970685317889799503809911129792,0.606871033488146083278991227417,0.193911159186147485922191435748,0.028417354801301336619800963490,0.005397407427922606970582819486,0.062027044125987529366028638833,0.312804993994233448972863698145,0.644612387177403323157420800271,0.512856975326175246188142864412,0.150524192998700373502529714642,0.015710328739062143915683478212,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.003866566714704468884361565628,0.056466878411227702794228378025,0.305580370448915117354005133166,0.638842372821403325922062776954,0.542784127274306471555576081300,0.199428103502484715958686933845,0.034195114751501783090237296392,0.003004828567809282417744309868,0.036718540231265403561700111368,0.193911159186147485922191435748,0.539407507237626315799161602627,0.890244596459464188065169309994,0.998084786973467807946747143433,0.879542452480196468300732703938,0.704167388165951257938957041915,0.581110040064810595694666517375,0.539407507237626315799161602627,0.581110040064810595694666517375,0.704167388165951257938957041915,0.879542452480196468300732703938,0.998084786973467807946747143433,0.890244596459464188065169309994,0.539407507237626315799161602627,0.193911159186147485922191435748,0.036718540231265403561700111368,0.003004828567809282417744309868,0.034195114751501783090237296392,0.199428103502484715958686933845,0.542784127274306471555576081300,0.638842372821403325922062776954,0.305580370448915117354005133166,0.056466878411227702794228378025,0.003866566714704468884361565628,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.014393607781830908276532454693,0.125941874360487621187232321063,0.444255814514749713950436671439,0.667000000000000037303493627405,0.454929834928519405412572496061,0.152427969332705803839900227103,0.027554381265135988871017858060,0.003004828567809282417744309868,0.028417354801301336619800963490,0.130395947587439803294628859476,0.354797416542333876599002451258,0.640958955183616896356113556976,0.863898307628227279941768301796,0.970685317889799503809911129792,0.998108264722803006918638857314,1.000000000000000000000000000000,0.998108264722803006918638857314,0.970685317889799503809911129792,0.863898307628227279941768301796,0.640958955183616896356113556976,0.354797416542333876599002451258,0.130395947587439803294628859476,0.028417354801301336619800963490,0.003004828567809282417744309868,0.027554381265135988871017858060,0.152427969332705803839900227103,0.454929834928519405412572496061,0.667000000000000037303493627405,0.444255814514749713950436671439,0.125941874360487621187232321063,0.014393607781830908276532454693,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.033198055150686286984829109770,0.198503129203038780614676284131,0.525906647878404465146218171867,0.659655326078040449289119351306,0.423482195985292575457492603164,0.152427969332705803839900227103,0.034195114751501783090237296392,0.005397407427922606970582819486,0.012601889579890421938523914491,0.053157552993335681346120935586,0.144480717365489341741380258100,0.277885821767910656809874581086,0.412307389132855084401541034822,0.506417255837276814034453309432,0.539407507237626315799161602627,0.506417255837276814034453309432,0.412307389132855084401541034822,0.277885821767910656809874581086,0.144480717365489341741380258100,0.053157552993335681346120935586,0.012601889579890421938523914491,0.005397407427922606970582819486,0.034195114751501783090237296392,0.152427969332705803839900227103,0.423482195985292575457492603164,0.659655326078040449289119351306,0.525906647878404465146218171867,0.198503129203038780614676284131,0.033198055150686286984829109770,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.005195210964754794734365983544,0.052486828606181840095601387475,0.243056739497610502231950135865,0.550994920849954827879457752715,0.659655326078040449289119351306,0.454929834928519405412572496061,0.199428103502484715958686933845,0.062027044125987529366028638833,0.015435852309432439646852408544,0.003492280597131325672877188993,0.010936165957362780493111742430,0.028417354801301336619800963490,0.053157552993335681346120935586,0.075591725639036669304182680662,0.084657988622529781186365482881,0.075591725639036669304182680662,0.053157552993335681346120935586,0.028417354801301336619800963490,0.010936165957362780493111742430,0.003492280597131325672877188993,0.015435852309432439646852408544,0.062027044125987529366028638833,0.199428103502484715958686933845,0.454929834928519405412572496061,0.659655326078040449289119351306,0.550994920849954827879457752715,0.243056739497610502231950135865,0.052486828606181840095601387475,0.005195210964754794734365983544,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.007614557614842068847260669884,0.060691515251315872325754696703,0.243056739497610502231950135865,0.525906647878404465146218171867,0.667000000000000037303493627405,0.542784127274306471555576081300,0.312804993994233448972863698145,0.141852466032250268401782022920,0.056466878411227369727320990478,0.022004757948541068396375308680,0.009308056895955259951724869438,0.004680371971332184834191370015,0.003004828567809282417744309868,0.002578568733028360499687225982,0.003004828567809282417744309868,0.004680371971332184834191370015,0.009308056895955259951724869438,0.022004757948541068396375308680,0.056466878411227369727320990478,0.141852466032250268401782022920,0.312804993994233448972863698145,0.542784127274306471555576081300,0.667000000000000037303493627405,0.525906647878404465146218171867,0.243056739497610502231950135865,0.060691515251315872325754696703,0.007614557614842068847260669884,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.007614557614842068847260669884,0.052486828606181840095601387475,0.198503129203038780614676284131,0.444255814514749713950436671439,0.638842372821403325922062776954,0.644612387177403323157420800271,0.500459054191348395868033094303,0.328301036417308733650344265698,0.199428103502484715958686933845,0.122145259306724929260212775262,0.081195504699406062520949944883,0.062027044125987529366028638833,0.056466878411227369727320990478,0.062027044125987529366028638833,0.081195504699406062520949944883,0.122145259306724929260212775262,0.199428103502484715958686933845,0.328301036417308733650344265698,0.500459054191348395868033094303,0.644612387177403323157420800271,0.638842372821403325922062776954,0.444255814514749713950436671439,0.198503129203038780614676284131,0.052486828606181840095601387475,0.007614557614842068847260669884,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.005195210964754794734365983544,0.033198055150686286984829109770,0.125941874360487621187232321063,0.305580370448915117354005133166,0.512856975326175246188142864412,0.645287014389146040826972239302,0.659655326078040449289119351306,0.591938786967272201344769655407,0.500459054191348395868033094303,0.423482195985292575457492603164,0.375687003729732682533182241968,0.359784807327497291318252337078,0.375687003729732682533182241968,0.423482195985292575457492603164,0.500459054191348395868033094303,0.591938786967272201344769655407,0.659655326078040449289119351306,0.645287014389146040826972239302,0.512856975326175246188142864412,0.305580370448915117354005133166,0.125941874360487621187232321063,0.033198055150686286984829109770,0.005195210964754794734365983544,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.014393607781830908276532454693,0.056466878411227702794228378025,0.150524192998700373502529714642,0.292543292568001878084515965384,0.444255814514749713950436671439,0.562947095984884793296032512444,0.631639347673919826497979101987,0.659784050050305825863006248255,0.666543693958222438489258365735,0.667000000000000037303493627405,0.666543693958222438489258365735,0.659784050050305825863006248255,0.631639347673919826497979101987,0.562947095984884793296032512444,0.444255814514749713950436671439,0.292543292568001878084515965384,0.150524192998700373502529714642,0.056466878411227702794228378025,0.014393607781830908276532454693,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.003866566714704468884361565628,0.015710328739062143915683478212,0.045221414044208166349569921749,0.097909965236922871789637667916,0.168662236819697219925018316644,0.243056739497610502231950135865,0.305580370448915117354005133166,0.345973370821185677304043792901,0.359784807327497291318252337078,0.345973370821185677304043792901,0.305580370448915117354005133166,0.243056739497610502231950135865,0.168662236819697219925018316644,0.097909965236922871789637667916,0.045221414044208166349569921749,0.015710328739062143915683478212,0.003866566714704468884361565628,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.006929064842820123991395941943,0.015710328739062143915683478212,0.028289150249923390029183067895,0.041916553600206796215132953876,0.052486828606181840095601387475,0.056466878411227702794228378025,0.052486828606181840095601387475,0.041916553600206796215132953876,0.028289150249923390029183067895,0.015710328739062143915683478212,0.006929064842820123991395941943,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0
-);
@@ -1,14 +0,0 @@
-const int R = 12;
-const int kernel_size = 26;
-const int kernel_length = 26*26;
-
-const float T = 10.0;
-
-const float m = 0.15;
-const float s = 0.015;
-
-
-const float kernel[kernel_length] = float[kernel_length](
-    // Kernel 4: Orbium
-     0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000034753974530056855590104403,0.000054079818553246697518301772,0.000073667460840171170195767036,0.000088428253962550737964087855,0.000093933034422676118385675537,0.000088428253962550737964087855,0.000073667460840171170195767036,0.000054079818553246697518301772,0.000034753974530056855590104403,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000042060200362951335225757815,0.000083225698762102193282302887,0.000142343439496023303585114927,0.000212902426929770130330232814,0.000281409266943344750326605475,0.000331475626361053322434246926,0.000349850709947656618274691720,0.000331475626361053322434246926,0.000281409266943344750326605475,0.000212902426929770130330232814,0.000142343439496023303585114927,0.000083225698762102193282302887,0.000042060200362951335225757815,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000026867996740168658353041520,0.000069283477325131278016720482,0.000150894271415532080487381661,0.000281409266943344750326605475,0.000455943895808694362424551283,0.000651107513767467641248309906,0.000830480566333308242894795104,0.000956433313132193699789007635,0.001001692185109341257098769518,0.000956433313132193699789007635,0.000830480566333308242894795104,0.000651107513767467641248309906,0.000455943895808694362424551283,0.000281409266943344750326605475,0.000150894271415532080487381661,0.000069283477325131278016720482,0.000026867996740168658353041520,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000030570314976624833646148827,0.000088428253962550737964087855,0.000212902426929770130330232814,0.000432708380360210251910219048,0.000754339149446291417494547815,0.001147752107743618210991543016,0.001551786063368379214519321430,0.001896415080018573401030024250,0.002125120274859489209046126135,0.002204818935451685179421899008,0.002125120274859489209046126135,0.001896415080018573401030024250,0.001551786063368379214519321430,0.001147752107743618210991543016,0.000754339149446291417494547815,0.000432708380360210251910219048,0.000212902426929770130330232814,0.000088428253962550737964087855,0.000030570314976624833646148827,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000026867996740168658353041520,0.000088428253962550737964087855,0.000238248272234484019527211984,0.000532305011010571422006010245,0.001001692185109341257098769518,0.001616969899822638277384068139,0.002286165904671761707478427894,0.002895344569808537375787960144,0.003358583554126586684374089486,0.003638407947842221996925804461,0.003730771865972241745079696074,0.003638407947842221996925804461,0.003358583554126586684374089486,0.002895344569808537375787960144,0.002286165904671761707478427894,0.001616969899822638277384068139,0.001001692185109341257098769518,0.000532305011010571422006010245,0.000238248272234484019527211984,0.000088428253962550737964087855,0.000026867996740168658353041520,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000069283477325131278016720482,0.000212902426929770130330232814,0.000532305011010571422006010245,0.001097321385677475910278011462,0.001896415080018573401030024250,0.002804728088348766926185540171,0.003638407947842221996925804461,0.004257881228270342550967786366,0.004629507967865740397650498039,0.004804682797216722547894995898,0.004853014339525533668573409329,0.004804682797216722547894995898,0.004629507967865740397650498039,0.004257881228270342550967786366,0.003638407947842221996925804461,0.002804728088348766926185540171,0.001896415080018573401030024250,0.001097321385677475910278011462,0.000532305011010571422006010245,0.000212902426929770130330232814,0.000069283477325131278016720482,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000042060200362951335225757815,0.000150894271415532080487381661,0.000432708380360210251910219048,0.001001692185109341257098769518,0.001896415080018573401030024250,0.002986841408779160322861656596,0.004001754684329655170627049188,0.004692576392535955515095125179,0.004986693602083332350904942842,0.004994941437633585758215648553,0.004901699573811469506923188533,0.004853014339525533668573409329,0.004901699573811469506923188533,0.004994941437633585758215648553,0.004986693602083332350904942842,0.004692576392535955515095125179,0.004001754684329655170627049188,0.002986841408779160322861656596,0.001896415080018573401030024250,0.001001692185109341257098769518,0.000432708380360210251910219048,0.000150894271415532080487381661,0.000042060200362951335225757815,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000083225698762102193282302887,0.000281409266943344750326605475,0.000754339149446291417494547815,0.001616969899822638277384068139,0.002804728088348766926185540171,0.004001754684329655170627049188,0.004804682797216722547894995898,0.005009014002498639317462192366,0.004728527218117404944119908095,0.004259052721898567983593864028,0.003874384585082192578031623498,0.003730771865972242612441434062,0.003874384585082192578031623498,0.004259052721898567983593864028,0.004728527218117404944119908095,0.005009014002498639317462192366,0.004804682797216722547894995898,0.004001754684329655170627049188,0.002804728088348766926185540171,0.001616969899822638277384068139,0.000754339149446291417494547815,0.000281409266943344750326605475,0.000083225698762102193282302887,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000034753974530056855590104403,0.000142343439496023303585114927,0.000455943895808694362424551283,0.001147752107743618210991543016,0.002286165904671761707478427894,0.003638407947842221996925804461,0.004692576392535955515095125179,0.005009014002498639317462192366,0.004567636297016995985953435877,0.003730771865972242612441434062,0.002920473153119950999317966378,0.002385932224468667942063326848,0.002204818935451685179421899008,0.002385932224468667942063326848,0.002920473153119950999317966378,0.003730771865972242612441434062,0.004567636297016995985953435877,0.005009014002498639317462192366,0.004692576392535955515095125179,0.003638407947842221996925804461,0.002286165904671761707478427894,0.001147752107743618210991543016,0.000455943895808694362424551283,0.000142343439496023303585114927,0.000034753974530056855590104403,0.000000000000000000000000000000,0.000054079818553246697518301772,0.000212902426929770130330232814,0.000651107513767467641248309906,0.001551786063368379214519321430,0.002895344569808537375787960144,0.004257881228270342550967786366,0.004986693602083332350904942842,0.004728527218117404944119908095,0.003730771865972242612441434062,0.002566212090858327812825656622,0.001666709600354328475735332660,0.001159044577300459739899185507,0.001001692185109341690779638512,0.001159044577300459739899185507,0.001666709600354328475735332660,0.002566212090858327812825656622,0.003730771865972242612441434062,0.004728527218117404944119908095,0.004986693602083332350904942842,0.004257881228270342550967786366,0.002895344569808537375787960144,0.001551786063368379214519321430,0.000651107513767467641248309906,0.000212902426929770130330232814,0.000054079818553246697518301772,0.000000000000000000000000000000,0.000073667460840171170195767036,0.000281409266943344750326605475,0.000830480566333308242894795104,0.001896415080018573401030024250,0.003358583554126586684374089486,0.004629507967865740397650498039,0.004994941437633585758215648553,0.004259052721898567983593864028,0.002920473153119950999317966378,0.001666709600354328475735332660,0.000852055364817571009132535220,0.000459229040985475230746137854,0.000349850709947656618274691720,0.000459229040985475230746137854,0.000852055364817571009132535220,0.001666709600354328475735332660,0.002920473153119950999317966378,0.004259052721898567983593864028,0.004994941437633585758215648553,0.004629507967865740397650498039,0.003358583554126586684374089486,0.001896415080018573401030024250,0.000830480566333308242894795104,0.000281409266943344750326605475,0.000073667460840171170195767036,0.000000000000000000000000000000,0.000088428253962550737964087855,0.000331475626361053322434246926,0.000956433313132193699789007635,0.002125120274859489209046126135,0.003638407947842221996925804461,0.004804682797216722547894995898,0.004901699573811469506923188533,0.003874384585082192578031623498,0.002385932224468667942063326848,0.001159044577300459739899185507,0.000459229040985475230746137854,0.000167192703614164986684539893,0.000093933034422676240358419941,0.000167192703614164986684539893,0.000459229040985475230746137854,0.001159044577300459739899185507,0.002385932224468667942063326848,0.003874384585082192578031623498,0.004901699573811469506923188533,0.004804682797216722547894995898,0.003638407947842221996925804461,0.002125120274859489209046126135,0.000956433313132193699789007635,0.000331475626361053322434246926,0.000088428253962550737964087855,0.000000000000000000000000000000,0.000093933034422676118385675537,0.000349850709947656618274691720,0.001001692185109341257098769518,0.002204818935451685179421899008,0.003730771865972241745079696074,0.004853014339525533668573409329,0.004853014339525533668573409329,0.003730771865972242612441434062,0.002204818935451685179421899008,0.001001692185109341690779638512,0.000349850709947656618274691720,0.000093933034422676240358419941,0.000019388359465263956188992717,0.000093933034422676240358419941,0.000349850709947656618274691720,0.001001692185109341690779638512,0.002204818935451685179421899008,0.003730771865972242612441434062,0.004853014339525533668573409329,0.004853014339525533668573409329,0.003730771865972241745079696074,0.002204818935451685179421899008,0.001001692185109341257098769518,0.000349850709947656618274691720,0.000093933034422676118385675537,0.000000000000000000000000000000,0.000088428253962550737964087855,0.000331475626361053322434246926,0.000956433313132193699789007635,0.002125120274859489209046126135,0.003638407947842221996925804461,0.004804682797216722547894995898,0.004901699573811469506923188533,0.003874384585082192578031623498,0.002385932224468667942063326848,0.001159044577300459739899185507,0.000459229040985475230746137854,0.000167192703614164986684539893,0.000093933034422676240358419941,0.000167192703614164986684539893,0.000459229040985475230746137854,0.001159044577300459739899185507,0.002385932224468667942063326848,0.003874384585082192578031623498,0.004901699573811469506923188533,0.004804682797216722547894995898,0.003638407947842221996925804461,0.002125120274859489209046126135,0.000956433313132193699789007635,0.000331475626361053322434246926,0.000088428253962550737964087855,0.000000000000000000000000000000,0.000073667460840171170195767036,0.000281409266943344750326605475,0.000830480566333308242894795104,0.001896415080018573401030024250,0.003358583554126586684374089486,0.004629507967865740397650498039,0.004994941437633585758215648553,0.004259052721898567983593864028,0.002920473153119950999317966378,0.001666709600354328475735332660,0.000852055364817571009132535220,0.000459229040985475230746137854,0.000349850709947656618274691720,0.000459229040985475230746137854,0.000852055364817571009132535220,0.001666709600354328475735332660,0.002920473153119950999317966378,0.004259052721898567983593864028,0.004994941437633585758215648553,0.004629507967865740397650498039,0.003358583554126586684374089486,0.001896415080018573401030024250,0.000830480566333308242894795104,0.000281409266943344750326605475,0.000073667460840171170195767036,0.000000000000000000000000000000,0.000054079818553246697518301772,0.000212902426929770130330232814,0.000651107513767467641248309906,0.001551786063368379214519321430,0.002895344569808537375787960144,0.004257881228270342550967786366,0.004986693602083332350904942842,0.004728527218117404944119908095,0.003730771865972242612441434062,0.002566212090858327812825656622,0.001666709600354328475735332660,0.001159044577300459739899185507,0.001001692185109341690779638512,0.001159044577300459739899185507,0.001666709600354328475735332660,0.002566212090858327812825656622,0.003730771865972242612441434062,0.004728527218117404944119908095,0.004986693602083332350904942842,0.004257881228270342550967786366,0.002895344569808537375787960144,0.001551786063368379214519321430,0.000651107513767467641248309906,0.000212902426929770130330232814,0.000054079818553246697518301772,0.000000000000000000000000000000,0.000034753974530056855590104403,0.000142343439496023303585114927,0.000455943895808694362424551283,0.001147752107743618210991543016,0.002286165904671761707478427894,0.003638407947842221996925804461,0.004692576392535955515095125179,0.005009014002498639317462192366,0.004567636297016995985953435877,0.003730771865972242612441434062,0.002920473153119950999317966378,0.002385932224468667942063326848,0.002204818935451685179421899008,0.002385932224468667942063326848,0.002920473153119950999317966378,0.003730771865972242612441434062,0.004567636297016995985953435877,0.005009014002498639317462192366,0.004692576392535955515095125179,0.003638407947842221996925804461,0.002286165904671761707478427894,0.001147752107743618210991543016,0.000455943895808694362424551283,0.000142343439496023303585114927,0.000034753974530056855590104403,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000083225698762102193282302887,0.000281409266943344750326605475,0.000754339149446291417494547815,0.001616969899822638277384068139,0.002804728088348766926185540171,0.004001754684329655170627049188,0.004804682797216722547894995898,0.005009014002498639317462192366,0.004728527218117404944119908095,0.004259052721898567983593864028,0.003874384585082192578031623498,0.003730771865972242612441434062,0.003874384585082192578031623498,0.004259052721898567983593864028,0.004728527218117404944119908095,0.005009014002498639317462192366,0.004804682797216722547894995898,0.004001754684329655170627049188,0.002804728088348766926185540171,0.001616969899822638277384068139,0.000754339149446291417494547815,0.000281409266943344750326605475,0.000083225698762102193282302887,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000042060200362951335225757815,0.000150894271415532080487381661,0.000432708380360210251910219048,0.001001692185109341257098769518,0.001896415080018573401030024250,0.002986841408779160322861656596,0.004001754684329655170627049188,0.004692576392535955515095125179,0.004986693602083332350904942842,0.004994941437633585758215648553,0.004901699573811469506923188533,0.004853014339525533668573409329,0.004901699573811469506923188533,0.004994941437633585758215648553,0.004986693602083332350904942842,0.004692576392535955515095125179,0.004001754684329655170627049188,0.002986841408779160322861656596,0.001896415080018573401030024250,0.001001692185109341257098769518,0.000432708380360210251910219048,0.000150894271415532080487381661,0.000042060200362951335225757815,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000069283477325131278016720482,0.000212902426929770130330232814,0.000532305011010571422006010245,0.001097321385677475910278011462,0.001896415080018573401030024250,0.002804728088348766926185540171,0.003638407947842221996925804461,0.004257881228270342550967786366,0.004629507967865740397650498039,0.004804682797216722547894995898,0.004853014339525533668573409329,0.004804682797216722547894995898,0.004629507967865740397650498039,0.004257881228270342550967786366,0.003638407947842221996925804461,0.002804728088348766926185540171,0.001896415080018573401030024250,0.001097321385677475910278011462,0.000532305011010571422006010245,0.000212902426929770130330232814,0.000069283477325131278016720482,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000026867996740168658353041520,0.000088428253962550737964087855,0.000238248272234484019527211984,0.000532305011010571422006010245,0.001001692185109341257098769518,0.001616969899822638277384068139,0.002286165904671761707478427894,0.002895344569808537375787960144,0.003358583554126586684374089486,0.003638407947842221996925804461,0.003730771865972241745079696074,0.003638407947842221996925804461,0.003358583554126586684374089486,0.002895344569808537375787960144,0.002286165904671761707478427894,0.001616969899822638277384068139,0.001001692185109341257098769518,0.000532305011010571422006010245,0.000238248272234484019527211984,0.000088428253962550737964087855,0.000026867996740168658353041520,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000030570314976624833646148827,0.000088428253962550737964087855,0.000212902426929770130330232814,0.000432708380360210251910219048,0.000754339149446291417494547815,0.001147752107743618210991543016,0.001551786063368379214519321430,0.001896415080018573401030024250,0.002125120274859489209046126135,0.002204818935451685179421899008,0.002125120274859489209046126135,0.001896415080018573401030024250,0.001551786063368379214519321430,0.001147752107743618210991543016,0.000754339149446291417494547815,0.000432708380360210251910219048,0.000212902426929770130330232814,0.000088428253962550737964087855,0.000030570314976624833646148827,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000026867996740168658353041520,0.000069283477325131278016720482,0.000150894271415532080487381661,0.000281409266943344750326605475,0.000455943895808694362424551283,0.000651107513767467641248309906,0.000830480566333308242894795104,0.000956433313132193699789007635,0.001001692185109341257098769518,0.000956433313132193699789007635,0.000830480566333308242894795104,0.000651107513767467641248309906,0.000455943895808694362424551283,0.000281409266943344750326605475,0.000150894271415532080487381661,0.000069283477325131278016720482,0.000026867996740168658353041520,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000042060200362951335225757815,0.000083225698762102193282302887,0.000142343439496023303585114927,0.000212902426929770130330232814,0.000281409266943344750326605475,0.000331475626361053322434246926,0.000349850709947656618274691720,0.000331475626361053322434246926,0.000281409266943344750326605475,0.000212902426929770130330232814,0.000142343439496023303585114927,0.000083225698762102193282302887,0.000042060200362951335225757815,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000034753974530056855590104403,0.000054079818553246697518301772,0.000073667460840171170195767036,0.000088428253962550737964087855,0.000093933034422676118385675537,0.000088428253962550737964087855,0.000073667460840171170195767036,0.000054079818553246697518301772,0.000034753974530056855590104403,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000,0.000000000000000000000000000000
-);
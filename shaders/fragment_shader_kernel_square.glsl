@@ -1,14 +0,0 @@
-const int R = 5;
-const int kernel_size = 11;
-const int kernel_length = 121;
-
-const float T = 10.0;
-
-const float m = 0.15;
-const float s = 0.015;
-
-
-const float kernel[kernel_length] = float[kernel_length](
-    // Kernel 1: square
-    0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.0,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602,0.008333333333333333217685101602
-);
@@ -0,0 +1,11 @@
+uniform float u_m;
+uniform float u_s;
+
+float bell(float x) {
+    return exp(-pow((x - u_m)/u_s, 2.0)/2.0);
+}
+
+// the bell around u_m, mapped to -1..1
+float growth(float U) {
+    return (bell(U)*2.0) - 1.0;
+}
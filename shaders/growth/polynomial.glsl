@@ -0,0 +1,7 @@
+uniform float u_m;
+uniform float u_s;
+
+// the polynomial bump around u_m, 3 u_s wide on each side, mapped to -1..1
+float growth(float U) {
+    return pow(max(0.0, 1.0 - pow(U - u_m, 2.0)/(9.0*u_s*u_s)), 4.0)*2.0 - 1.0;
+}
@@ -0,0 +1,9 @@
+uniform float u_b1;
+uniform float u_b2;
+uniform float u_s1;
+uniform float u_s2;
+
+// +1 in the birth range, -1 outside the survival range, 0 otherwise
+float growth(float U) {
+    return float(int(U >= u_b1 && U <= u_b2) - int(U <= u_s1 || U >= u_s2));
+}
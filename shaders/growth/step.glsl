@@ -0,0 +1,7 @@
+uniform float u_m;
+uniform float u_s;
+
+// 1 within u_s of u_m, -1 elsewhere
+float growth(float U) {
+    return abs(U - u_m) <= u_s ? 1.0 : -1.0;
+}
@@ -0,0 +1,18 @@
+#include "common/state"
+
+uniform int u_R;
+
+// mean state of the (2R+1)^2 square around the cell, every neighbour weighing the same and
+// the cell itself not at all
+float neighbourhood_average() {
+    float sum = 0.0;
+    for (int dy = -u_R; dy <= u_R; dy++) {
+        for (int dx = -u_R; dx <= u_R; dx++) {
+            if (dx != 0 || dy != 0) {
+                sum += neighbour(dx, dy);
+            }
+        }
+    }
+    int rows = 2*u_R + 1;
+    return sum / float(rows*rows - 1);
+}
@@ -0,0 +1,39 @@
+#version 300 es
+precision highp float;
+
+#include "common/state"
+
+// row 0: birth, row 1: survival, indexed by the number of live neighbours
+uniform sampler2D u_rule;
+
+// a cell holds its remaining lifetime as level / (u_states - 1): 1.0 is alive, 0.0 dead
+// and the levels in between are the refractory states of a dying cell
+uniform float u_states;
+
+void main() {
+    float top = u_states - 1.0;
+
+    int alive_neighbors = 0;
+    for (int dy = -1; dy <= 1; dy++) {
+        for (int dx = -1; dx <= 1; dx++) {
+            // only live cells count, refractory ones are ignored
+            if ((dx != 0 || dy != 0) && floor(neighbour(dx, dy) * top + 0.5) == top) {
+                alive_neighbors += 1;
+            }
+        }
+    }
+
+    float level = floor(neighbour(0, 0) * top + 0.5);
+
+    float new_level;
+    if (level == 0.0) {
+        new_level = texelFetch(u_rule, ivec2(alive_neighbors, 0), 0).r * top;
+    } else if (level == top && texelFetch(u_rule, ivec2(alive_neighbors, 1), 0).r > 0.0) {
+        new_level = top;
+    } else {
+        new_level = level - 1.0;
+    }
+    float new_state = new_level / top;
+
+    outColor = vec4(vec3(new_state), 1.0);
+}
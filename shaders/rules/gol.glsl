@@ -0,0 +1,24 @@
+#version 300 es
+precision highp float;
+
+#include "common/state"
+
+// row 0: birth, row 1: survival, indexed by the number of live neighbours
+uniform sampler2D u_rule;
+
+void main() {
+    int alive_neighbors = 0;
+    for (int dy = -1; dy <= 1; dy++) {
+        for (int dx = -1; dx <= 1; dx++) {
+            if ((dx != 0 || dy != 0) && neighbour(dx, dy) > 0.0) {
+                alive_neighbors += 1;
+            }
+        }
+    }
+
+    bool is_alive = neighbour(0, 0) > 0.0;
+
+    float new_state = texelFetch(u_rule, ivec2(alive_neighbors, int(is_alive)), 0).r;
+
+    outColor = vec4(vec3(new_state), 1.0);
+}
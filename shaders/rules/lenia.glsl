@@ -0,0 +1,35 @@
+#version 300 es
+precision highp float;
+
+// R, the kernel radius, is defined when the shader is assembled, so the convolution has
+// constant bounds. So is GROWTH, the growth function: `gn` of the animal library.
+
+#include "common/state"
+#if GROWTH == 1
+#include "growth/polynomial"
+#elif GROWTH == 3
+#include "growth/step"
+#else
+#include "growth/bell"
+#endif
+
+// kernel weights, (2R+1) x (2R+1) texels with the centre at (R, R)
+uniform highp sampler2D u_kernel;
+
+uniform float u_T;
+
+void main() {
+    // this is basically a convolution 2D
+    float U = 0.0;
+    for (int y = -R; y <= R; y++) {
+        for (int x = -R; x <= R; x++) {
+            float weight = texelFetch(u_kernel, ivec2(x + R, y + R), 0).r;
+            U += neighbour(x, y) * weight;
+        }
+    }
+
+    // clamp the new state to 0.0 or 1.0
+    float new_state = clamp(neighbour(0, 0) + 1.0/u_T * growth(U), 0.0, 1.0);
+
+    outColor = vec4(vec3(new_state), 1.0);
+}
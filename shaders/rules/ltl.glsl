@@ -0,0 +1,40 @@
+#version 300 es
+precision highp float;
+
+#include "common/state"
+
+uniform int u_R;
+// 0: Moore, 1: von Neumann, 2: circular
+uniform int u_neighbourhood;
+uniform int u_include_center;
+// row 0: birth, row 1: survival, indexed by the number of live cells in the neighbourhood
+uniform sampler2D u_rule;
+
+bool in_neighbourhood(int x, int y) {
+    if (u_neighbourhood == 1) {
+        return abs(x) + abs(y) <= u_R;
+    } else if (u_neighbourhood == 2) {
+        return x*x + y*y <= u_R*u_R;
+    }
+    return true;
+}
+
+void main() {
+    int U = 0;
+    for (int y = -u_R; y <= u_R; y++) {
+        for (int x = -u_R; x <= u_R; x++) {
+            if (in_neighbourhood(x, y)) {
+                U += int(neighbour(x, y) > 0.0);
+            }
+        }
+    }
+
+    bool is_alive = neighbour(0, 0) > 0.0;
+    if (u_include_center == 0) {
+        U -= int(is_alive);
+    }
+
+    float new_state = texelFetch(u_rule, ivec2(U, int(is_alive)), 0).r;
+
+    outColor = vec4(vec3(new_state), 1.0);
+}
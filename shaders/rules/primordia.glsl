@@ -0,0 +1,18 @@
+#version 300 es
+precision highp float;
+
+#include "neighbourhood/average"
+#include "growth/primordia"
+
+uniform float u_states;
+
+void main() {
+    float U = neighbourhood_average();
+
+    float new_state = neighbour(0, 0) * u_states + growth(U);
+
+    // clamp the new state to 0.0 or 1.0
+    new_state = clamp(new_state, 0.0, u_states)/u_states;
+
+    outColor = vec4(vec3(new_state), 1.0);
+}
@@ -1,6 +1,6 @@
 #version 300 es
 
-in vec4 position;
+layout(location = 0) in vec4 position;
 
 void main() {
     gl_Position = vec4(position.xy, 0.0, 1.0);
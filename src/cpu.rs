@@ -0,0 +1,386 @@
+// CPU reference implementation of the rules in `shaders/`, so generations can be computed
+// and checked without a WebGL2 context. Each cell holds the value the shaders keep in the
+// red channel of the state texture; rows are stored bottom-up like the texture.
+
+use crate::rules::{LeniaParams, Neighbourhood, PrimordiaParams, Rule};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<f32>,
+}
+
+impl Grid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![0.0; width * height],
+        }
+    }
+
+    pub fn from_cells(width: usize, height: usize, cells: Vec<f32>) -> Result<Self, String> {
+        if cells.len() != width * height {
+            return Err(format!("Expected {} cells, got {}", width * height, cells.len()));
+        }
+        Ok(Self { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn cells(&self) -> &[f32] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [f32] {
+        &mut self.cells
+    }
+
+    // RGBA/UNSIGNED_BYTE texture data with the state in every colour channel
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(self.cells.len() * 4);
+        for &value in &self.cells {
+            let value = (value * 255.0) as u8;
+            data.extend_from_slice(&[value, value, value, 255]);
+        }
+        data
+    }
+
+    // inverse of to_rgba8, reading the state from the red channel
+    pub fn from_rgba8(width: usize, height: usize, data: &[u8]) -> Result<Self, String> {
+        let cells = data.chunks_exact(4).map(|pixel| pixel[0] as f32 / 255.0).collect();
+        Self::from_cells(width, height, cells)
+    }
+
+    // RGBA/FLOAT texture data, laid out like to_rgba8
+    pub fn to_rgba32f(&self) -> Vec<f32> {
+        self.cells.iter().flat_map(|&value| [value, value, value, 1.0]).collect()
+    }
+
+    pub fn from_rgba32f(width: usize, height: usize, data: &[f32]) -> Result<Self, String> {
+        let cells = data.chunks_exact(4).map(|pixel| pixel[0]).collect();
+        Self::from_cells(width, height, cells)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> f32 {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: f32) {
+        self.cells[y * self.width + x] = value;
+    }
+
+    // a width x height grid with this one's cells around its centre, cropped or padded with
+    // dead cells
+    pub fn resized(&self, width: usize, height: usize) -> Self {
+        let mut grid = Self::new(width, height);
+        let offset_x = (width as i64 - self.width as i64) / 2;
+        let offset_y = (height as i64 - self.height as i64) / 2;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let (to_x, to_y) = (x as i64 + offset_x, y as i64 + offset_y);
+                if (0..width as i64).contains(&to_x) && (0..height as i64).contains(&to_y) {
+                    grid.set(to_x as usize, to_y as usize, self.get(x, y));
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells.iter().filter(|&&v| v > 0.0).count()
+    }
+
+    // the shaders wrap texture coordinates, so the world is a torus
+    fn wrapped(&self, x: i64, y: i64) -> f32 {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        self.cells[y * self.width + x]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Engine {
+    rule: Rule,
+    grid: Grid,
+    generation: u64,
+}
+
+impl Engine {
+    pub fn new(rule: Rule, grid: Grid) -> Self {
+        Self {
+            rule,
+            grid,
+            generation: 0,
+        }
+    }
+
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn step(&mut self) {
+        self.grid = step(&self.grid, &self.rule);
+        self.generation += 1;
+    }
+
+    pub fn run(&mut self, generations: u64) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+}
+
+pub fn step(grid: &Grid, rule: &Rule) -> Grid {
+    let (birth, survival) = rule.transition_table().unwrap_or_default();
+    let mut next = Grid::new(grid.width, grid.height);
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let current = grid.get(x, y);
+            let value = match rule {
+                Rule::LifeLike(_) => {
+                    let count = neighbour_count(grid, x, y, 1, Neighbourhood::Moore, false);
+                    transition(current, count, &birth, &survival)
+                }
+                Rule::Generations { states, .. } => generations(grid, x, y, current, *states, &birth, &survival),
+                Rule::LargerThanLife(params) => {
+                    let count = neighbour_count(grid, x, y, params.radius, params.neighbourhood, params.include_center);
+                    transition(current, count, &birth, &survival)
+                }
+                Rule::Primordia { params, states } => {
+                    let states = *states as f32;
+                    let growth = primordia_growth(neighbour_average(grid, x, y, params.radius), params);
+                    (current * states + growth).clamp(0.0, states) / states
+                }
+                Rule::PrimordiaTime { params, time_step } => {
+                    let growth = primordia_growth(neighbour_average(grid, x, y, params.radius), params);
+                    (current + growth / time_step).clamp(0.0, 1.0)
+                }
+                Rule::Lenia(params) => lenia(grid, x, y, current, params),
+            };
+            next.set(x, y, value);
+        }
+    }
+    next
+}
+
+fn neighbour_count(
+    grid: &Grid,
+    x: usize,
+    y: usize,
+    radius: i32,
+    neighbourhood: Neighbourhood,
+    include_center: bool,
+) -> usize {
+    let mut count = 0;
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            if !neighbourhood.contains(dx, dy, radius) || (!include_center && (dx, dy) == (0, 0)) {
+                continue;
+            }
+            if grid.wrapped(x as i64 + dx as i64, y as i64 + dy as i64) > 0.0 {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn transition(current: f32, count: usize, birth: &[bool], survival: &[bool]) -> f32 {
+    let table = if current > 0.0 { survival } else { birth };
+    table.get(count).copied().unwrap_or(false) as i32 as f32
+}
+
+// A Generations cell holds its remaining lifetime as level / (states - 1): 1.0 is alive,
+// lower levels are refractory and only count down. Only live cells are counted as neighbours.
+fn generations(grid: &Grid, x: usize, y: usize, current: f32, states: i32, birth: &[bool], survival: &[bool]) -> f32 {
+    let top = (states - 1) as f32;
+    let level = |value: f32| (value * top).round();
+    let mut count = 0;
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            if (dx, dy) != (0, 0) && level(grid.wrapped(x as i64 + dx, y as i64 + dy)) == top {
+                count += 1;
+            }
+        }
+    }
+    let level = level(current);
+    if level == 0.0 {
+        transition(0.0, count, birth, survival)
+    } else if level == top && transition(1.0, count, birth, survival) > 0.0 {
+        1.0
+    } else {
+        (level - 1.0) / top
+    }
+}
+
+fn neighbour_average(grid: &Grid, x: usize, y: usize, radius: i32) -> f32 {
+    let r = radius as i64;
+    let mut sum = 0.0;
+    for dy in -r..=r {
+        for dx in -r..=r {
+            if (dx, dy) != (0, 0) {
+                sum += grid.wrapped(x as i64 + dx, y as i64 + dy);
+            }
+        }
+    }
+    sum / ((2 * r + 1) * (2 * r + 1) - 1) as f32
+}
+
+fn primordia_growth(u: f32, params: &PrimordiaParams) -> f32 {
+    let (b1, b2) = params.birth;
+    let (s1, s2) = params.survival;
+    ((u >= b1 && u <= b2) as i32 - (u <= s1 || u >= s2) as i32) as f32
+}
+
+fn lenia(grid: &Grid, x: usize, y: usize, current: f32, params: &LeniaParams) -> f32 {
+    // same indexing as shaders/rules/lenia.glsl: offsets run from -R to R
+    let kernel = &params.kernel;
+    let mut u = 0.0;
+    for (i, weight) in kernel.weights.iter().enumerate() {
+        if *weight == 0.0 {
+            continue;
+        }
+        let i = i as i32;
+        let dx = (i % kernel.size - kernel.radius) as i64;
+        let dy = (i / kernel.size - kernel.radius) as i64;
+        u += grid.wrapped(x as i64 + dx, y as i64 + dy) * weight;
+    }
+    let growth = params.growth.value(u, params.mu, params.sigma);
+    (current + growth / params.time_step).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presets::Preset;
+    use crate::random::Random;
+    use crate::rules::LifeLikeParams;
+
+    fn life() -> Rule {
+        Rule::LifeLike(LifeLikeParams::default())
+    }
+
+    fn grid_with(width: usize, height: usize, cells: &[(usize, usize)]) -> Grid {
+        let mut grid = Grid::new(width, height);
+        for &(x, y) in cells {
+            grid.set(x, y, 1.0);
+        }
+        grid
+    }
+
+    // cells drawn from 0, 1 / levels, ..., 1
+    fn random_grid(width: usize, height: usize, levels: u32, seed: u32) -> Grid {
+        let mut random = Random::new(seed);
+        let cells = (0..width * height)
+            .map(|_| (random.next_f64() * (levels + 1) as f64).floor() as f32 / levels as f32)
+            .collect();
+        Grid::from_cells(width, height, cells).unwrap()
+    }
+
+    fn shifted(grid: &Grid, dx: i64, dy: i64) -> Grid {
+        let mut shifted = Grid::new(grid.width(), grid.height());
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                shifted.set(x, y, grid.wrapped(x as i64 - dx, y as i64 - dy));
+            }
+        }
+        shifted
+    }
+
+    #[test]
+    fn blinker_has_period_two() {
+        let vertical = grid_with(5, 5, &[(2, 1), (2, 2), (2, 3)]);
+        let horizontal = grid_with(5, 5, &[(1, 2), (2, 2), (3, 2)]);
+        let mut engine = Engine::new(life(), vertical.clone());
+        engine.step();
+        assert_eq!(engine.grid(), &horizontal);
+        engine.step();
+        assert_eq!(engine.grid(), &vertical);
+        assert_eq!(engine.generation(), 2);
+    }
+
+    #[test]
+    fn glider_moves_diagonally_across_the_edge() {
+        // starts against the top right corner of an 8x8 torus, so it wraps in both directions
+        let start = grid_with(8, 8, &[(6, 5), (7, 6), (5, 7), (6, 7), (7, 7)]);
+        let mut engine = Engine::new(life(), start.clone());
+        for moves in 1..=8 {
+            engine.run(4);
+            assert_eq!(engine.grid(), &shifted(&start, moves, moves), "after {} generations", moves * 4);
+        }
+        assert_eq!(engine.grid(), &start);
+    }
+
+    #[test]
+    fn default_ltl_matches_the_old_shader() {
+        // the hard-coded shader: 11x11 count with the cell itself, b 34..45, s 34..58
+        let grid = random_grid(32, 32, 1, 7);
+        let next = step(&grid, &Rule::larger_than_life());
+        for y in 0..32 {
+            for x in 0..32 {
+                let mut count = 0;
+                for dy in -5..=5 {
+                    for dx in -5..=5 {
+                        count += (grid.wrapped(x as i64 + dx, y as i64 + dy) > 0.0) as i32;
+                    }
+                }
+                let alive = (grid.get(x, y) > 0.0) as i32;
+                let born = (34..=45).contains(&count) as i32;
+                let dies = !(34..=58).contains(&count) as i32;
+                let expected = (alive + born - dies).clamp(0, 1) as f32;
+                assert_eq!(next.get(x, y), expected, "cell {}, {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn primordia_matches_the_old_shader() {
+        // the hard-coded shader: 1/8 weights around the cell, then the clamp to 0..states
+        let states = 12.0;
+        let grid = random_grid(24, 24, 12, 3);
+        let next = step(&grid, &Rule::primordia(PrimordiaParams::default(), 12).unwrap());
+        for y in 0..24 {
+            for x in 0..24 {
+                let mut u = 0.0;
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        if (dx, dy) != (0, 0) {
+                            u += grid.wrapped(x as i64 + dx, y as i64 + dy) * (1.0 / 8.0);
+                        }
+                    }
+                }
+                let growth = ((0.20..=0.25).contains(&u) as i32 - (u <= 0.18 || u >= 0.33) as i32) as f32;
+                let expected = (grid.get(x, y) * states + growth).clamp(0.0, states) / states;
+                assert_eq!(next.get(x, y), expected, "cell {}, {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn lenia_stays_within_zero_and_one() {
+        for id in ["lenia", "orbium", "geminium"] {
+            let rule = Preset::find(id).unwrap().rule;
+            let mut engine = Engine::new(rule, random_grid(48, 48, 255, 11));
+            for _ in 0..10 {
+                engine.step();
+                assert!(engine.grid().cells().iter().all(|value| (0.0..=1.0).contains(value)), "{}", id);
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Serialisation of a grid: RLE for the binary rules, 8-bit grayscale PNG for the continuous
+// ones and raw bytes for scripting. RLE and PNG are written top row first, as displayed.
+
+use crate::cpu::Grid;
+
+impl Grid {
+    // Cells of a rule of `states` states (see Rule::rle_states). With two, live cells are those
+    // with a non-zero value, as in the GoL and LtL shaders, written as b and o. With more they
+    // are Generations levels, written with Golly's multi-state tags: . is dead, A alive and
+    // B onwards the dying states.
+    pub fn to_rle(&self, rule: Option<&str>, states: i32) -> String {
+        let mut rle = format!("x = {}, y = {}", self.width(), self.height());
+        if let Some(rule) = rule {
+            rle.push_str(&format!(", rule = {}", rule));
+        }
+        rle.push('\n');
+
+        let top = (states - 1) as f32;
+        let tag = |value: f32| -> String {
+            if states <= 2 {
+                return String::from(if value > 0.0 { "o" } else { "b" });
+            }
+            match top - (value.clamp(0.0, 1.0) * top).round() {
+                dying if dying >= top => String::from("."),
+                dying => state_tag(dying as usize + 1),
+            }
+        };
+        let dead = tag(0.0);
+
+        let mut runs: Vec<(usize, String)> = Vec::new();
+        for y in (0..self.height()).rev() {
+            let mut x = 0;
+            while x < self.width() {
+                let current = tag(self.get(x, y));
+                let start = x;
+                while x < self.width() && tag(self.get(x, y)) == current {
+                    x += 1;
+                }
+                push_run(&mut runs, x - start, current);
+            }
+            // dead cells at the end of a row are implied
+            if matches!(runs.last(), Some((_, last)) if *last == dead) {
+                runs.pop();
+            }
+            push_run(&mut runs, 1, String::from("$"));
+        }
+        while matches!(runs.last(), Some((_, last)) if last == "$") {
+            runs.pop();
+        }
+
+        let mut line = String::new();
+        for (count, tag) in runs.into_iter().chain(std::iter::once((1, String::from("!")))) {
+            let item = if count > 1 { format!("{}{}", count, tag) } else { tag };
+            if line.len() + item.len() > 70 {
+                rle.push_str(&line);
+                rle.push('\n');
+                line.clear();
+            }
+            line.push_str(&item);
+        }
+        rle.push_str(&line);
+        rle.push('\n');
+        rle
+    }
+
+    // one byte per cell, bottom row first like the state texture
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.cells().iter().map(|&v| (v.clamp(0.0, 1.0) * 255.0) as u8).collect()
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut scanlines = Vec::with_capacity((self.width() + 1) * self.height());
+        let bytes = self.to_bytes();
+        for row in bytes.chunks(self.width().max(1)).rev() {
+            scanlines.push(0); // filter type: none
+            scanlines.extend_from_slice(row);
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(self.width() as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height() as u32).to_be_bytes());
+        // bit depth 8, grayscale, deflate, no filtering, no interlace
+        header.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+fn push_run(runs: &mut Vec<(usize, String)>, count: usize, tag: String) {
+    match runs.last_mut() {
+        Some((n, last)) if *last == tag => *n += count,
+        _ => runs.push((count, tag)),
+    }
+}
+
+// A to X for states 1 to 24, then pA to pX, qA and so on, as the RLE reader takes them
+fn state_tag(state: usize) -> String {
+    let (prefix, tag) = ((state - 1) / 24, (state - 1) % 24);
+    let tag = (b'A' + tag as u8) as char;
+    match prefix {
+        0 => tag.to_string(),
+        prefix => format!("{}{}", (b'p' + prefix as u8 - 1) as char, tag),
+    }
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+// zlib stream made of uncompressed deflate blocks; keeps the writer dependency free
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xFFFF).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    out.extend_from_slice(&((b << 16) | a).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,334 @@
+// Initial conditions for a new world, generated from a seed so that a run can be replayed.
+// The region generators (noise, square, disk, soups, symmetric) make live cells with a
+// probability of `density`; the fields (smooth noise, gradients) keep their values for the
+// continuous rules and are turned into live and dead cells for the binary ones.
+
+use crate::cpu::Grid;
+use crate::patterns;
+use crate::random::Random;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Generator {
+    // the whole world
+    Noise,
+    // noise in a centred square, `size` times the shorter side of the world
+    Square { size: f64 },
+    // noise in a centred disk, `size` times the shorter side across
+    Disk { size: f64 },
+    // value noise with features about `scale` cells across, tiling the torus
+    Smooth { scale: f64 },
+    // `count` patches of noise, `size` cells square, at random places
+    Soups { count: usize, size: usize },
+    // a centred patch of noise, `size` cells square, with the given symmetry
+    Symmetric { symmetry: Symmetry, size: usize },
+    Gradient(GradientDirection),
+    // a pattern of patterns::LIBRARY in the centre, such as a Lenia creature
+    Pattern(&'static str),
+}
+
+// C2: half turn, C4: quarter turns, D8: quarter turns and reflections
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    C2,
+    C4,
+    D8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientDirection {
+    // left to right
+    Horizontal,
+    // bottom to top
+    Vertical,
+    // centre outwards
+    Radial,
+}
+
+impl Generator {
+    // noise, square, disk, smooth, soups, c2, c4, d8, gradient_x, gradient_y or radial; `size`
+    // replaces the default square or disk fraction, smooth noise scale, soup patch size or
+    // symmetric soup size
+    pub fn from_name(name: &str, size: Option<f64>) -> Result<Self, String> {
+        let fraction = |default: f64| match size {
+            Some(size) if !(size > 0.0 && size <= 1.0) => Err(format!("{} size {} outside 0..1", name, size)),
+            _ => Ok(size.unwrap_or(default)),
+        };
+        let cells = |default: usize| match size {
+            Some(size) if size.is_nan() || size < 1.0 => Err(format!("{} size {} is less than a cell", name, size)),
+            _ => Ok(size.map_or(default, |size| size as usize)),
+        };
+        let symmetric = |symmetry| cells(32).map(|size| Generator::Symmetric { symmetry, size });
+        match name {
+            "noise" => Ok(Generator::Noise),
+            "square" => Ok(Generator::Square { size: fraction(0.25)? }),
+            "disk" => Ok(Generator::Disk { size: fraction(0.25)? }),
+            "smooth" => Ok(Generator::Smooth { scale: cells(16)? as f64 }),
+            "soups" => Ok(Generator::Soups { count: 8, size: cells(16)? }),
+            "c2" => symmetric(Symmetry::C2),
+            "c4" => symmetric(Symmetry::C4),
+            "d8" => symmetric(Symmetry::D8),
+            "gradient_x" => Ok(Generator::Gradient(GradientDirection::Horizontal)),
+            "gradient_y" => Ok(Generator::Gradient(GradientDirection::Vertical)),
+            "radial" => Ok(Generator::Gradient(GradientDirection::Radial)),
+            _ => Err(format!("Unknown initial condition {}", name)),
+        }
+    }
+}
+
+// what a world is seeded with: the generator, the seed of its randomness and the fraction of
+// live cells
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Seeding {
+    pub generator: Generator,
+    pub seed: u32,
+    pub density: f64,
+}
+
+impl Seeding {
+    // `continuous` rules get the values of the smooth noise and gradients as they are
+    pub fn grid(&self, width: usize, height: usize, continuous: bool) -> Grid {
+        let mut random = Random::new(self.seed);
+        let mut grid = Grid::new(width, height);
+        let density = self.density;
+        let shorter = width.min(height) as f64;
+        let (center_x, center_y) = (width as f64 / 2.0, height as f64 / 2.0);
+
+        match self.generator {
+            Generator::Noise => fill(&mut grid, &mut random, density, |_, _| true),
+            Generator::Square { size } => {
+                let half = size * shorter / 2.0;
+                fill(&mut grid, &mut random, density, |x, y| {
+                    (x as f64 + 0.5 - center_x).abs() < half && (y as f64 + 0.5 - center_y).abs() < half
+                });
+            }
+            Generator::Disk { size } => {
+                let radius = size * shorter / 2.0;
+                fill(&mut grid, &mut random, density, |x, y| {
+                    (x as f64 + 0.5 - center_x).hypot(y as f64 + 0.5 - center_y) < radius
+                });
+            }
+            Generator::Smooth { scale } => {
+                let noise = smooth_noise(width, height, scale, &mut random);
+                for (cell, value) in grid.cells_mut().iter_mut().zip(noise) {
+                    if value > 1.0 - density {
+                        *cell = if continuous { value as f32 } else { 1.0 };
+                    }
+                }
+            }
+            Generator::Soups { count, size } => {
+                for _ in 0..count {
+                    let left = (random.next_f64() * width as f64) as usize;
+                    let bottom = (random.next_f64() * height as f64) as usize;
+                    for y in 0..size.min(height) {
+                        for x in 0..size.min(width) {
+                            let value = live(&mut random, density);
+                            grid.set((left + x) % width, (bottom + y) % height, value);
+                        }
+                    }
+                }
+            }
+            Generator::Symmetric { symmetry, size } => {
+                let size = size.min(width).min(height);
+                let values: Vec<f32> = (0..size * size).map(|_| live(&mut random, density)).collect();
+                let left = (width - size) / 2;
+                let bottom = (height - size) / 2;
+                for y in 0..size {
+                    for x in 0..size {
+                        let (x0, y0) = symmetry.representative(x, y, size);
+                        grid.set(left + x, bottom + y, values[y0 * size + x0]);
+                    }
+                }
+            }
+            Generator::Gradient(direction) => {
+                let max_radius = center_x.hypot(center_y);
+                for y in 0..height {
+                    for x in 0..width {
+                        let (fx, fy) = (x as f64 + 0.5, y as f64 + 0.5);
+                        let value = match direction {
+                            GradientDirection::Horizontal => fx / width as f64,
+                            GradientDirection::Vertical => fy / height as f64,
+                            GradientDirection::Radial => (fx - center_x).hypot(fy - center_y) / max_radius,
+                        } * density;
+                        let value = if continuous { value as f32 } else { live(&mut random, value) };
+                        grid.set(x, y, value);
+                    }
+                }
+            }
+            Generator::Pattern(name) => {
+                if let Some(pattern) = patterns::from_name(name) {
+                    patterns::place_centered(&mut grid, &pattern);
+                }
+            }
+        }
+        grid
+    }
+}
+
+impl Symmetry {
+    // the cell whose value (x, y) copies: the first, in row order, among the images of (x, y)
+    // under the symmetry
+    fn representative(self, x: usize, y: usize, size: usize) -> (usize, usize) {
+        let last = size - 1;
+        let rotate = |(x, y): (usize, usize)| (last - y, x);
+        let mirror = |(x, y): (usize, usize)| (last - x, y);
+        let mut images = Vec::with_capacity(8);
+        let mut point = (x, y);
+        for turn in 0..4 {
+            let kept = match self {
+                Symmetry::C2 => turn % 2 == 0,
+                Symmetry::C4 | Symmetry::D8 => true,
+            };
+            if kept {
+                images.push(point);
+                if self == Symmetry::D8 {
+                    images.push(mirror(point));
+                }
+            }
+            point = rotate(point);
+        }
+        images.into_iter().min_by_key(|&(x, y)| (y, x)).unwrap()
+    }
+}
+
+fn live(random: &mut Random, density: f64) -> f32 {
+    if random.next_f64() < density {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn fill(grid: &mut Grid, random: &mut Random, density: f64, inside: impl Fn(usize, usize) -> bool) {
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            if inside(x, y) {
+                let value = live(random, density);
+                grid.set(x, y, value);
+            }
+        }
+    }
+}
+
+// Three octaves of value noise: random values on a lattice, blended with smoothstep. Lattice
+// periods divide the world size so the noise wraps like the world. Values are in 0..1.
+fn smooth_noise(width: usize, height: usize, scale: f64, random: &mut Random) -> Vec<f64> {
+    let mut noise = vec![0.0; width * height];
+    let mut total = 0.0;
+    for octave in 0..3 {
+        let weight = 0.5f64.powi(octave);
+        let cell = (scale / 2.0f64.powi(octave)).max(1.0);
+        let columns = ((width as f64 / cell).round() as usize).max(1);
+        let rows = ((height as f64 / cell).round() as usize).max(1);
+        let lattice: Vec<f64> = (0..columns * rows).map(|_| random.next_f64()).collect();
+        let at = |column: usize, row: usize| lattice[(row % rows) * columns + column % columns];
+
+        for y in 0..height {
+            let v = y as f64 * rows as f64 / height as f64;
+            let (row, ty) = (v.floor() as usize, smoothstep(v.fract()));
+            for x in 0..width {
+                let u = x as f64 * columns as f64 / width as f64;
+                let (column, tx) = (u.floor() as usize, smoothstep(u.fract()));
+                let bottom = at(column, row) + (at(column + 1, row) - at(column, row)) * tx;
+                let top = at(column, row + 1) + (at(column + 1, row + 1) - at(column, row + 1)) * tx;
+                noise[y * width + x] += (bottom + (top - bottom) * ty) * weight;
+            }
+        }
+        total += weight;
+    }
+    for value in &mut noise {
+        *value /= total;
+    }
+    noise
+}
+
+fn smoothstep(t: f64) -> f64 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeding(generator: Generator, seed: u32, density: f64) -> Seeding {
+        Seeding { generator, seed, density }
+    }
+
+    #[test]
+    fn symmetric_soups_are_invariant() {
+        let size = 32;
+        for (symmetry, seed) in [(Symmetry::C2, 1), (Symmetry::C4, 2), (Symmetry::D8, 3)] {
+            let grid = seeding(Generator::Symmetric { symmetry, size }, seed, 0.5).grid(64, 48, false);
+            let (left, bottom) = ((64 - size) / 2, (48 - size) / 2);
+            let at = |(x, y): (usize, usize)| grid.get(left + x, bottom + y);
+            let last = size - 1;
+            assert!(grid.population() > 0);
+            for y in 0..size {
+                for x in 0..size {
+                    let value = at((x, y));
+                    assert_eq!(value, at((last - x, last - y)), "{:?} half turn at {} {}", symmetry, x, y);
+                    if symmetry != Symmetry::C2 {
+                        assert_eq!(value, at((last - y, x)), "{:?} quarter turn at {} {}", symmetry, x, y);
+                    }
+                    if symmetry == Symmetry::D8 {
+                        assert_eq!(value, at((last - x, y)), "{:?} reflection at {} {}", symmetry, x, y);
+                        assert_eq!(value, at((y, x)), "{:?} diagonal at {} {}", symmetry, x, y);
+                    }
+                }
+            }
+            // and nothing outside the patch
+            let patch = (0..size).flat_map(|y| (0..size).map(move |x| (x, y))).filter(|&p| at(p) > 0.0).count();
+            assert_eq!(patch, grid.population());
+        }
+    }
+
+    #[test]
+    fn density_is_respected() {
+        let population = |density| seeding(Generator::Noise, 5, density).grid(128, 128, false).population();
+        assert_eq!(population(0.0), 0);
+        assert_eq!(population(1.0), 128 * 128);
+        let fraction = population(0.3) as f64 / (128.0 * 128.0);
+        assert!((fraction - 0.3).abs() < 0.02, "{}", fraction);
+    }
+
+    #[test]
+    fn regions_are_respected() {
+        // a quarter of 64 is 16 cells across, centred
+        let square = seeding(Generator::Square { size: 0.25 }, 1, 1.0).grid(64, 64, false);
+        for y in 0..64 {
+            for x in 0..64 {
+                let inside = (24..40).contains(&x) && (24..40).contains(&y);
+                assert_eq!(square.get(x, y), if inside { 1.0 } else { 0.0 }, "square at {} {}", x, y);
+            }
+        }
+
+        let disk = seeding(Generator::Disk { size: 0.25 }, 1, 1.0).grid(64, 64, false);
+        for y in 0..64 {
+            for x in 0..64 {
+                let distance = (x as f64 + 0.5 - 32.0).hypot(y as f64 + 0.5 - 32.0);
+                assert_eq!(disk.get(x, y) > 0.0, distance < 8.0, "disk at {} {}", x, y);
+            }
+        }
+
+        let soups = seeding(Generator::Soups { count: 3, size: 8 }, 1, 1.0).grid(64, 64, false);
+        assert!(soups.population() > 0);
+        assert!(soups.population() <= 3 * 8 * 8);
+    }
+
+    #[test]
+    fn same_seed_same_grid() {
+        let generators = [
+            Generator::Noise,
+            Generator::Square { size: 0.5 },
+            Generator::Disk { size: 0.5 },
+            Generator::Smooth { scale: 8.0 },
+            Generator::Soups { count: 4, size: 8 },
+            Generator::Symmetric { symmetry: Symmetry::D8, size: 16 },
+            Generator::Gradient(GradientDirection::Radial),
+        ];
+        for generator in generators {
+            let grid = |seed| seeding(generator, seed, 0.5).grid(48, 32, false);
+            assert_eq!(grid(9).cells(), grid(9).cells(), "{:?}", generator);
+            assert_ne!(grid(9).cells(), grid(10).cells(), "{:?}", generator);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// What goes wrong on the GPU side. The exports hand these to JS as objects with a `kind`, a
+// `message` and the fields of the variant, so that the page can show which line of which
+// shader failed instead of a bare info log.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GlError {
+    // `shader` is the module under shaders/ the source was assembled from
+    Compile { shader: String, log: String, lines: Vec<LogLine> },
+    Link { shader: String, log: String },
+    FramebufferIncomplete { status: u32 },
+    MissingUniform { shader: String, name: String },
+    ContextLost,
+    ExtensionMissing { name: String },
+    // any other error code of getError, `location` being where it was checked
+    Call { code: u32, location: String },
+}
+
+// a message of a compile log, with the line of the assembled source it points at
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    // "error" or "warning"
+    pub severity: String,
+    // from 1, in the source after includes and defines, as shader_source returns it
+    pub line: Option<usize>,
+    pub message: String,
+    pub source: Option<String>,
+}
+
+impl GlError {
+    pub fn compile(shader: &str, source: &str, log: &str) -> Self {
+        GlError::Compile {
+            shader: shader.to_string(),
+            log: log.to_string(),
+            lines: log
+                .lines()
+                .filter(|line| !line.trim().is_empty() && line.trim() != "\0")
+                .map(|line| LogLine::parse(line, source))
+                .collect(),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            GlError::Compile { .. } => "compile",
+            GlError::Link { .. } => "link",
+            GlError::FramebufferIncomplete { .. } => "framebuffer_incomplete",
+            GlError::MissingUniform { .. } => "missing_uniform",
+            GlError::ContextLost => "context_lost",
+            GlError::ExtensionMissing { .. } => "extension_missing",
+            GlError::Call { .. } => "call",
+        }
+    }
+}
+
+impl LogLine {
+    // "ERROR: 0:12: 'foo' : undeclared identifier" as browsers built on ANGLE write it, or
+    // "0:12(5): error: `foo' undeclared" as Mesa does; lines in neither form are kept whole
+    fn parse(text: &str, source: &str) -> Self {
+        let text = text.trim().trim_end_matches('\0');
+        let (mut severity, rest) = match text.split_once(": ") {
+            Some((severity @ ("ERROR" | "WARNING"), rest)) => (severity.to_lowercase(), rest),
+            _ => ("error".to_string(), text),
+        };
+        let location = rest.split_once(": ").and_then(|(location, message)| {
+            let (_, line) = location.split_once(':')?;
+            let line = line.split('(').next()?.parse::<usize>().ok()?;
+            Some((line, message))
+        });
+        let Some((line, message)) = location else {
+            return Self {
+                severity,
+                line: None,
+                message: text.to_string(),
+                source: None,
+            };
+        };
+        let message = match message.split_once(": ") {
+            Some((mesa_severity @ ("error" | "warning"), message)) => {
+                severity = mesa_severity.to_string();
+                message
+            }
+            _ => message,
+        };
+        Self {
+            severity,
+            line: Some(line),
+            message: message.to_string(),
+            // log lines count from 1
+            source: line.checked_sub(1).and_then(|index| source.lines().nth(index)).map(str::to_string),
+        }
+    }
+}
+
+impl fmt::Display for GlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GlError::Compile { shader, log, lines } => {
+                write!(f, "Failed to compile {}", shader)?;
+                if lines.is_empty() {
+                    return write!(f, ": {}", log);
+                }
+                for line in lines {
+                    match (line.line, &line.source) {
+                        (Some(number), Some(source)) => {
+                            write!(f, "\nline {}, {}: {}\n    {}", number, line.severity, line.message, source.trim())?
+                        }
+                        _ => write!(f, "\n{}", line.message)?,
+                    }
+                }
+                Ok(())
+            }
+            GlError::Link { shader, log } => write!(f, "Failed to link {}: {}", shader, log),
+            GlError::FramebufferIncomplete { status } => write!(f, "Framebuffer is not complete: 0x{:X}", status),
+            GlError::MissingUniform { shader, name } => write!(f, "{} has no uniform {}", shader, name),
+            GlError::ContextLost => write!(f, "The WebGL context was lost"),
+            GlError::ExtensionMissing { name } => write!(f, "{} is not available", name),
+            GlError::Call { code, location } => write!(f, "WebGL error at {}: 0x{:X}", location, code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "#version 300 es\nprecision highp float;\nvoid main() {\n    foo = 1.0;\n}\n";
+
+    #[test]
+    fn angle_lines_are_parsed() {
+        let line = LogLine::parse("ERROR: 0:4: 'foo' : undeclared identifier", SOURCE);
+        assert_eq!(line.severity, "error");
+        assert_eq!(line.line, Some(4));
+        assert_eq!(line.message, "'foo' : undeclared identifier");
+        assert_eq!(line.source.as_deref(), Some("    foo = 1.0;"));
+
+        let warning = LogLine::parse("WARNING: 0:2: 'extension' : not supported\0", SOURCE);
+        assert_eq!(warning.severity, "warning");
+        assert_eq!(warning.line, Some(2));
+        assert_eq!(warning.source.as_deref(), Some("precision highp float;"));
+    }
+
+    #[test]
+    fn mesa_lines_are_parsed() {
+        let line = LogLine::parse("0:4(5): error: `foo' undeclared", SOURCE);
+        assert_eq!(line.severity, "error");
+        assert_eq!(line.line, Some(4));
+        assert_eq!(line.message, "`foo' undeclared");
+        assert_eq!(line.source.as_deref(), Some("    foo = 1.0;"));
+
+        let warning = LogLine::parse("0:3(1): warning: unused variable", SOURCE);
+        assert_eq!(warning.severity, "warning");
+        assert_eq!(warning.line, Some(3));
+    }
+
+    #[test]
+    fn other_lines_are_kept_whole() {
+        let line = LogLine::parse("ERROR: 1 compilation errors.  No code generated.", SOURCE);
+        assert_eq!(line.severity, "error");
+        assert_eq!(line.line, None);
+        assert_eq!(line.message, "ERROR: 1 compilation errors.  No code generated.");
+        assert_eq!(line.source, None);
+    }
+
+    #[test]
+    fn lines_past_the_source_have_no_text() {
+        let line = LogLine::parse("ERROR: 0:40: '' : syntax error", SOURCE);
+        assert_eq!(line.line, Some(40));
+        assert_eq!(line.source, None);
+        let zero = LogLine::parse("ERROR: 0:0: '' : syntax error", SOURCE);
+        assert_eq!(zero.line, Some(0));
+        assert_eq!(zero.source, None);
+    }
+
+    #[test]
+    fn compile_skips_blank_log_lines() {
+        let log = "ERROR: 0:4: 'foo' : undeclared identifier\n\nERROR: 1 compilation errors.  No code generated.\n\0";
+        let GlError::Compile { lines, .. } = GlError::compile("rules/gol", SOURCE, log) else {
+            panic!("not a compile error");
+        };
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].line, Some(4));
+        assert_eq!(lines[1].line, None);
+    }
+}
@@ -0,0 +1,162 @@
+// Creatures in the JSON format of the Lenia animal library:
+//
+//   {"code": "O2u", "name": "Orbium unicaudatus",
+//    "params": {"R": 13, "T": 10, "b": "1", "m": 0.15, "s": 0.015, "kn": 1, "gn": 1},
+//    "cells": "7.MD6.qL$6.pKqEqFURpApBRAJ$..."}
+//
+// `cells` is RLE where `.` is 0 and `A`..`yO` are 1..255, scaled to 0..1. `kn` picks the
+// kernel core and `gn` the growth function (see GrowthFunction), both the polynomial if missing.
+
+use serde_json::Value;
+
+use crate::rle;
+use crate::rules::{GrowthFunction, Kernel, KernelCore, LeniaParams, MAX_RADIUS};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animal {
+    pub code: String,
+    pub name: String,
+    pub params: LeniaParams,
+    // top row first, like the patterns in patterns.rs
+    pub cells: Vec<Vec<f64>>,
+}
+
+impl Animal {
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(text).map_err(|e| format!("Invalid JSON: {}", e))?;
+        Self::from_value(&value)
+    }
+
+    fn from_value(value: &Value) -> Result<Self, String> {
+        let text = |key: &str| value.get(key).and_then(Value::as_str).unwrap_or_default().to_string();
+        let params = value
+            .get("params")
+            .ok_or_else(|| String::from("Animal has no params"))?;
+        let number = |key: &str| {
+            params
+                .get(key)
+                .and_then(Value::as_f64)
+                .ok_or_else(|| format!("Animal params missing {}", key))
+        };
+
+        let radius = number("R")? as i32;
+        if !(1..=MAX_RADIUS).contains(&radius) {
+            return Err(format!("Invalid kernel radius {}, expected 1 to {}", radius, MAX_RADIUS));
+        }
+        let core = match params.get("kn").and_then(Value::as_i64) {
+            Some(kn) => KernelCore::from_index(kn).ok_or_else(|| format!("Unknown kernel core kn={}", kn))?,
+            None => KernelCore::Polynomial,
+        };
+        let growth = match params.get("gn").and_then(Value::as_i64) {
+            Some(gn) => GrowthFunction::from_index(gn).ok_or_else(|| format!("Unknown growth function gn={}", gn))?,
+            None => GrowthFunction::Polynomial,
+        };
+        let peaks = match params.get("b") {
+            Some(b) => parse_peaks(b)?,
+            None => vec![1.0],
+        };
+
+        let cells = value
+            .get("cells")
+            .and_then(Value::as_str)
+            .ok_or_else(|| String::from("Animal has no cells"))?;
+        let mut cells: Vec<Vec<f64>> = rle::parse_body(cells, rle::MAX_SIZE, rle::MAX_SIZE)?
+            .into_iter()
+            .map(|row| row.into_iter().map(|state| state as f64 / 255.0).collect())
+            .collect();
+        let width = cells.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut cells {
+            row.resize(width, 0.0);
+        }
+
+        Ok(Self {
+            code: text("code"),
+            name: text("name"),
+            params: LeniaParams::new(
+                Kernel::generate(radius, &peaks, core),
+                number("T")? as f32,
+                number("m")? as f32,
+                number("s")? as f32,
+                growth,
+            )?,
+            cells,
+        })
+    }
+}
+
+// accepts a single animal or a whole library; library entries without cells are section headers
+pub fn animals_from_json(text: &str) -> Result<Vec<Animal>, String> {
+    let value: Value = serde_json::from_str(text).map_err(|e| format!("Invalid JSON: {}", e))?;
+    match value {
+        Value::Array(entries) => entries
+            .iter()
+            .filter(|entry| entry.get("cells").is_some())
+            .map(Animal::from_value)
+            .collect(),
+        value => Ok(vec![Animal::from_value(&value)?]),
+    }
+}
+
+// "1,1/3,2/3" or [1, 0.33, 0.66]
+fn parse_peaks(value: &Value) -> Result<Vec<f32>, String> {
+    let fraction = |text: &str| -> Result<f32, String> {
+        let parse = |v: &str| v.trim().parse::<f32>().map_err(|e| format!("Invalid peak {}: {}", text, e));
+        match text.split_once('/') {
+            Some((numerator, denominator)) => Ok(parse(numerator)? / parse(denominator)?),
+            None => parse(text),
+        }
+    };
+    match value {
+        Value::String(text) => text.split(',').map(fraction).collect(),
+        Value::Number(number) => Ok(vec![number.as_f64().unwrap_or(1.0) as f32]),
+        Value::Array(values) => values
+            .iter()
+            .map(|v| v.as_f64().map(|v| v as f32).ok_or_else(|| format!("Invalid peak {}", v)))
+            .collect(),
+        _ => Err(format!("Invalid kernel peaks {}", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn animal(params: &str) -> Result<Animal, String> {
+        Animal::from_json(&format!(r#"{{"code": "O2u", "name": "Orbium", "params": {{{}}}, "cells": "2.A$.BC!"}}"#, params))
+    }
+
+    #[test]
+    fn reads_the_params_and_cells() {
+        let animal = animal(r#""R": 13, "T": 10, "b": "1", "m": 0.15, "s": 0.015, "kn": 1, "gn": 1"#).unwrap();
+        assert_eq!(animal.params.kernel.radius, 13);
+        assert_eq!((animal.params.time_step, animal.params.mu, animal.params.sigma), (10.0, 0.15, 0.015));
+        assert_eq!(animal.params.growth, GrowthFunction::Polynomial);
+        assert_eq!(animal.cells, vec![vec![0.0, 0.0, 1.0 / 255.0], vec![0.0, 2.0 / 255.0, 3.0 / 255.0]]);
+    }
+
+    #[test]
+    fn gn_picks_the_growth_function() {
+        for (gn, growth) in [(1, GrowthFunction::Polynomial), (2, GrowthFunction::Gaussian), (3, GrowthFunction::Step)] {
+            let animal = animal(&format!(r#""R": 5, "T": 10, "m": 0.15, "s": 0.015, "gn": {}"#, gn)).unwrap();
+            assert_eq!(animal.params.growth, growth);
+        }
+        assert!(animal(r#""R": 5, "T": 10, "m": 0.15, "s": 0.015, "gn": 4"#).is_err());
+    }
+
+    #[test]
+    fn time_step_and_width_must_be_positive() {
+        assert!(animal(r#""R": 5, "T": 0, "m": 0.15, "s": 0.015"#).is_err());
+        assert!(animal(r#""R": 5, "T": 10, "m": 0.15, "s": 0"#).is_err());
+        assert!(animal(r#""R": 5, "T": 10, "m": 0.15, "s": -0.015"#).is_err());
+    }
+
+    #[test]
+    fn growth_functions_peak_at_mu() {
+        for growth in [GrowthFunction::Polynomial, GrowthFunction::Gaussian, GrowthFunction::Step] {
+            assert_eq!(growth.value(0.15, 0.15, 0.015), 1.0);
+            assert_eq!(growth.value(0.6, 0.15, 0.015), -1.0);
+            let near = growth.value(0.16, 0.15, 0.015);
+            assert!(near > -1.0 && near <= 1.0, "{:?}", growth);
+        }
+    }
+}
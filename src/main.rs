@@ -0,0 +1,252 @@
+// Headless runner: advances a rule on the CPU and writes the final grid to a file.
+
+use std::{env, fs, process};
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use gol_webgl::cpu::{Engine, Grid};
+use gol_webgl::lenia::Animal;
+use gol_webgl::patterns;
+use gol_webgl::presets::Preset;
+use gol_webgl::generators::{Generator, Seeding};
+use gol_webgl::rle::{self, RlePattern};
+use gol_webgl::rules::Rule;
+
+const USAGE: &str = "usage: gol-webgl --output FILE [--rule PRESET|RULESTRING]
+                 [--generations N] [--width W] [--height H] [--states N]
+                 [--pattern FILE] [--init NAME] [--init-size S] [--seed N] [--density D]
+                 [--set NAME=VALUE]...
+       gol-webgl --list-presets
+
+Pattern and output files hold one row per line, top row first, with the cell
+values separated by spaces. Pattern files ending in .rle are read as Life RLE, whose rule
+header sets the rule unless --rule is given, files ending in .json as a Lenia animal, which
+also sets the rule. Output files ending in .rle, .png or
+.bin are written as RLE, grayscale PNG or one byte per cell (bottom row first).
+--rule takes a preset id (gol by default, --list-presets shows them all), a B/S rulestring
+such as B36/S23 or an HROT one such as R5,C0,S34-58,B34-45,NM. --states sets the number of
+states of the primordia preset (12 by default) and is refused for other rules. Worlds are at
+most 4096 cells wide and high (256x256 by default).
+Without a pattern the world starts as the preset does, or from the --init generator: noise,
+square, disk, smooth, soups, c2, c4, d8, gradient_x, gradient_y or radial, with a fraction D
+of live cells (0.5 by default). --init-size sets the size of the square, disk, soups or smooth noise
+features. The seed is printed so that the run can be repeated with --seed.
+--set overrides a rule parameter by its shader name, e.g. --set b1=30 or --set m=0.16.";
+
+struct Options {
+    // None: the rule of an RLE pattern's header, or gol
+    rule: Option<String>,
+    generations: u64,
+    width: usize,
+    height: usize,
+    // only for the primordia preset
+    states: Option<i32>,
+    pattern: Option<String>,
+    init: Option<String>,
+    init_size: Option<f64>,
+    seed: Option<u32>,
+    density: f64,
+    params: Vec<(String, f32)>,
+    output: String,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        rule: None,
+        generations: 100,
+        width: 256,
+        height: 256,
+        states: None,
+        pattern: None,
+        init: None,
+        init_size: None,
+        seed: None,
+        density: 0.5,
+        params: Vec::new(),
+        output: String::new(),
+    };
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+        match flag.as_str() {
+            "--rule" => options.rule = Some(value.clone()),
+            "--generations" => options.generations = parse_value(flag, value)?,
+            "--width" => options.width = parse_size(flag, value)?,
+            "--height" => options.height = parse_size(flag, value)?,
+            "--states" => options.states = Some(parse_value(flag, value)?),
+            "--pattern" => options.pattern = Some(value.clone()),
+            "--init" => options.init = Some(value.clone()),
+            "--init-size" => options.init_size = Some(parse_value(flag, value)?),
+            "--seed" => options.seed = Some(parse_value(flag, value)?),
+            "--density" => {
+                options.density = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|density| (0.0..=1.0).contains(density))
+                    .ok_or_else(|| format!("Invalid value for {}: expected a fraction in 0..1", flag))?;
+            }
+            "--output" => options.output = value.clone(),
+            "--set" => {
+                let (name, param) = value
+                    .split_once('=')
+                    .ok_or_else(|| format!("Expected NAME=VALUE for --set, got {}", value))?;
+                let param = param
+                    .parse::<f32>()
+                    .map_err(|e| format!("Invalid value for {}: {}", name, e))?;
+                options.params.push((name.to_string(), param));
+            }
+            _ => return Err(format!("Unknown option {}", flag)),
+        }
+    }
+
+    if options.output.is_empty() {
+        return Err(String::from("--output is required"));
+    }
+    Ok(options)
+}
+
+fn parse_value<T: FromStr>(flag: &str, value: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    value.parse().map_err(|e| format!("Invalid value for {}: {}", flag, e))
+}
+
+fn parse_size(flag: &str, value: &str) -> Result<usize, String> {
+    match parse_value(flag, value)? {
+        0 => Err(format!("Invalid value for {}: the world needs at least one cell", flag)),
+        size if size > rle::MAX_SIZE => Err(format!("Invalid value for {}: at most {} cells", flag, rle::MAX_SIZE)),
+        size => Ok(size),
+    }
+}
+
+// the cells of a pattern file; the rule header of an RLE file replaces `rule` if `header_rule`
+fn read_pattern(path: &str, rule: &mut Rule, header_rule: bool) -> Result<Vec<Vec<f64>>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+    if path.ends_with(".rle") {
+        let pattern = RlePattern::parse(&text)?;
+        if let (Some(header), true) = (&pattern.rule, header_rule) {
+            *rule = Rule::parse(header)?;
+        }
+        return Ok(pattern.to_pattern(rule.rle_states()));
+    }
+    let pattern = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.split_whitespace()
+                .map(|value| value.parse::<f64>().map_err(|e| format!("Invalid cell {}: {}", value, e)))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if pattern.is_empty() {
+        return Err(format!("{} contains no cells", path));
+    }
+    if pattern.iter().any(|row| row.len() != pattern[0].len()) {
+        return Err(format!("{} has rows of different lengths", path));
+    }
+    Ok(pattern)
+}
+
+fn write_grid(path: &str, grid: &Grid, rule: &Rule) -> Result<(), String> {
+    let write = |data: &[u8]| fs::write(path, data).map_err(|e| format!("Cannot write {}: {}", path, e));
+    if path.ends_with(".rle") {
+        return write(grid.to_rle(rule.rulestring().as_deref(), rule.rle_states()).as_bytes());
+    } else if path.ends_with(".png") {
+        return write(&grid.to_png());
+    } else if path.ends_with(".bin") {
+        return write(&grid.to_bytes());
+    }
+
+    let mut text = String::new();
+    for y in (0..grid.height()).rev() {
+        let row: Vec<String> = (0..grid.width()).map(|x| grid.get(x, y).to_string()).collect();
+        text.push_str(&row.join(" "));
+        text.push('\n');
+    }
+    write(text.as_bytes())
+}
+
+// seed for runs without --seed, from the clock
+fn clock_seed() -> u32 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() ^ d.as_secs() as u32)
+        .unwrap_or(0)
+}
+
+fn run(options: Options) -> Result<(), String> {
+    // rulestrings start from noise
+    let rule_name = options.rule.as_deref().unwrap_or("gol");
+    let (mut rule, start) = match (Preset::find(rule_name), options.states) {
+        (Some(preset), Some(states)) => (preset.rule(states)?, preset.start),
+        (Some(preset), None) => (preset.rule.clone(), preset.start),
+        (None, _) => (Rule::parse(rule_name)?, Generator::Noise),
+    };
+    if options.states.is_some() && !matches!(rule, Rule::Primordia { .. }) {
+        return Err(format!("--states only applies to the primordia preset, not {}", rule_name));
+    }
+
+    let grid = match &options.pattern {
+        // a Lenia animal brings its own kernel and growth parameters
+        Some(path) if path.ends_with(".json") => {
+            let text = fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+            let animal = Animal::from_json(&text)?;
+            let mut grid = Grid::new(options.width, options.height);
+            patterns::place_centered(&mut grid, &animal.cells);
+            rule = Rule::Lenia(animal.params);
+            grid
+        }
+        Some(path) => {
+            // an explicit --rule wins over the header
+            let pattern = read_pattern(path, &mut rule, options.rule.is_none())?;
+            let mut grid = Grid::new(options.width, options.height);
+            patterns::place_centered(&mut grid, &pattern);
+            grid
+        }
+        None => {
+            let seeding = Seeding {
+                generator: match &options.init {
+                    Some(name) => Generator::from_name(name, options.init_size)?,
+                    None => start,
+                },
+                seed: options.seed.unwrap_or_else(clock_seed),
+                density: options.density,
+            };
+            eprintln!("seed {}", seeding.seed);
+            seeding.grid(options.width, options.height, !rule.is_binary())
+        }
+    };
+
+    for (name, value) in &options.params {
+        rule.set_param(name, *value)?;
+    }
+
+    let mut engine = Engine::new(rule, grid);
+    engine.run(options.generations);
+    write_grid(&options.output, engine.grid(), engine.rule())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return;
+    }
+    if args.iter().any(|arg| arg == "--list-presets") {
+        for preset in Preset::all() {
+            println!("{:16} {}", preset.id, preset.name);
+        }
+        return;
+    }
+
+    let result = parse_args(&args).and_then(run);
+    if let Err(error) = result {
+        eprintln!("gol-webgl: {}\n\n{}", error, USAGE);
+        process::exit(1);
+    }
+}
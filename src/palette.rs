@@ -0,0 +1,188 @@
+// Palettes for the display pass: a state in 0..1 is looked up in a 256-entry colour table,
+// uploaded as a 256x1 texture. Tables are built from gradient stops, either one of the named
+// ones below or custom stops such as "0:#000000, 0.5:#ff8000, 1:#ffffff".
+
+pub const PALETTE_SIZE: usize = 256;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    colours: Vec<[u8; 3]>,
+}
+
+const VIRIDIS: [(f32, u32); 11] = [
+    (0.0, 0x440154),
+    (0.1, 0x482475),
+    (0.2, 0x414487),
+    (0.3, 0x355f8d),
+    (0.4, 0x2a788e),
+    (0.5, 0x21918c),
+    (0.6, 0x22a884),
+    (0.7, 0x44bf70),
+    (0.8, 0x7ad151),
+    (0.9, 0xbddf26),
+    (1.0, 0xfde725),
+];
+
+const INFERNO: [(f32, u32); 11] = [
+    (0.0, 0x000004),
+    (0.1, 0x160b39),
+    (0.2, 0x420a68),
+    (0.3, 0x6a176e),
+    (0.4, 0x932667),
+    (0.5, 0xbc3754),
+    (0.6, 0xdd513a),
+    (0.7, 0xf37819),
+    (0.8, 0xfca50a),
+    (0.9, 0xf6d746),
+    (1.0, 0xfcffa4),
+];
+
+impl Palette {
+    // grayscale, viridis and inferno, plus the looks the rule shaders used to bake in:
+    // fire (Generations), amber (Primordia) and gold (Lenia)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let palette = match name {
+            "grayscale" => Self::from_fn(|v| [v, v, v]),
+            "viridis" => Self::from_stops(&rgb_stops(&VIRIDIS)).ok()?,
+            "inferno" => Self::from_stops(&rgb_stops(&INFERNO)).ok()?,
+            // white for live cells, yellow to dark red through the refractory states
+            "fire" => Self::from_fn(|v| if v >= 1.0 { [1.0, 1.0, 1.0] } else { [v, v * v, 0.0] }),
+            "amber" => Self::from_fn(|v| [v, v / 2.0, 0.0]),
+            "gold" => Self::from_fn(|v| [v, v, 0.0]),
+            _ => return None,
+        };
+        Some(palette)
+    }
+
+    // linear interpolation between stops, which must be in 0..1 and in increasing order
+    pub fn from_stops(stops: &[(f32, [u8; 3])]) -> Result<Self, String> {
+        if stops.is_empty() {
+            return Err(String::from("A palette needs at least one stop"));
+        }
+        for pair in stops.windows(2) {
+            if pair[0].0 > pair[1].0 {
+                return Err(format!("Palette stops out of order: {} after {}", pair[1].0, pair[0].0));
+            }
+        }
+        if let Some(&(position, _)) = stops.iter().find(|(position, _)| !(0.0..=1.0).contains(position)) {
+            return Err(format!("Palette stop {} outside 0..1", position));
+        }
+
+        let colours = (0..PALETTE_SIZE)
+            .map(|i| {
+                let value = i as f32 / (PALETTE_SIZE - 1) as f32;
+                let after = stops.iter().position(|&(position, _)| position >= value);
+                match after {
+                    Some(0) => stops[0].1,
+                    None => stops[stops.len() - 1].1,
+                    Some(i) => {
+                        let (low, from) = stops[i - 1];
+                        let (high, to) = stops[i];
+                        let t = if high > low { (value - low) / (high - low) } else { 1.0 };
+                        let channel = |c: usize| (from[c] as f32 + (to[c] as f32 - from[c] as f32) * t).round() as u8;
+                        [channel(0), channel(1), channel(2)]
+                    }
+                }
+            })
+            .collect();
+        Ok(Self { colours })
+    }
+
+    // "0:#000000, 0.5:#ff8000, 1:#ffffff"
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let stops = text
+            .split(',')
+            .map(|stop| {
+                let invalid = || format!("Invalid palette stop {}, expected POSITION:#RRGGBB", stop.trim());
+                let (position, colour) = stop.split_once(':').ok_or_else(invalid)?;
+                let position = position.trim().parse::<f32>().map_err(|_| invalid())?;
+                let colour = colour.trim().strip_prefix('#').ok_or_else(invalid)?;
+                if colour.len() != 6 {
+                    return Err(invalid());
+                }
+                let colour = u32::from_str_radix(colour, 16).map_err(|_| invalid())?;
+                Ok((position, rgb(colour)))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Self::from_stops(&stops)
+    }
+
+    fn from_fn(colour: impl Fn(f32) -> [f32; 3]) -> Self {
+        let colours = (0..PALETTE_SIZE)
+            .map(|i| colour(i as f32 / (PALETTE_SIZE - 1) as f32).map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8))
+            .collect();
+        Self { colours }
+    }
+
+    pub fn colour(&self, value: f32) -> [u8; 3] {
+        let index = (value.clamp(0.0, 1.0) * (PALETTE_SIZE - 1) as f32).round() as usize;
+        self.colours[index]
+    }
+
+    // RGBA/UNSIGNED_BYTE data for the 256x1 lookup texture
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        self.colours.iter().flat_map(|&[r, g, b]| [r, g, b, 255]).collect()
+    }
+}
+
+fn rgb(colour: u32) -> [u8; 3] {
+    [(colour >> 16) as u8, (colour >> 8) as u8, colour as u8]
+}
+
+fn rgb_stops(stops: &[(f32, u32)]) -> Vec<(f32, [u8; 3])> {
+    stops.iter().map(|&(position, colour)| (position, rgb(colour))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_are_interpolated() {
+        let palette = Palette::parse("0:#000000, 0.5:#ff8000, 1:#ffffff").unwrap();
+        assert_eq!(palette.colour(0.0), [0, 0, 0]);
+        assert_eq!(palette.colour(1.0), [255, 255, 255]);
+        // 0.5 and 0.25 fall between table entries, so allow a step of rounding
+        let close = |value: f32, expected: [u8; 3]| {
+            let colour = palette.colour(value);
+            (0..3).all(|c| colour[c].abs_diff(expected[c]) <= 2)
+        };
+        assert!(close(0.5, [255, 128, 0]));
+        assert!(close(0.25, [128, 64, 0]));
+        assert!(close(0.75, [255, 192, 128]));
+    }
+
+    #[test]
+    fn ends_take_the_outer_stops() {
+        let palette = Palette::from_stops(&[(0.25, [10, 20, 30]), (0.75, [40, 50, 60])]).unwrap();
+        assert_eq!(palette.colour(0.0), [10, 20, 30]);
+        assert_eq!(palette.colour(0.1), [10, 20, 30]);
+        assert_eq!(palette.colour(0.9), [40, 50, 60]);
+        assert_eq!(palette.colour(1.0), [40, 50, 60]);
+
+        let single = Palette::parse("0.5:#123456").unwrap();
+        assert_eq!(single.colour(0.0), [0x12, 0x34, 0x56]);
+        assert_eq!(single.colour(1.0), [0x12, 0x34, 0x56]);
+    }
+
+    #[test]
+    fn invalid_stops_are_rejected() {
+        assert!(Palette::from_stops(&[]).is_err());
+        assert!(Palette::parse("1:#ffffff, 0:#000000").is_err());
+        assert!(Palette::parse("0:#000000, 1.5:#ffffff").is_err());
+        assert!(Palette::parse("-0.1:#000000, 1:#ffffff").is_err());
+        assert!(Palette::parse("0:#00000g, 1:#ffffff").is_err());
+        assert!(Palette::parse("0:#fff, 1:#ffffff").is_err());
+        assert!(Palette::parse("0:000000, 1:#ffffff").is_err());
+        assert!(Palette::parse("#000000").is_err());
+        assert!(Palette::parse("x:#000000").is_err());
+    }
+
+    #[test]
+    fn texture_data_is_rgba() {
+        let rgba = Palette::parse("0:#000000, 1:#ffffff").unwrap().to_rgba8();
+        assert_eq!(rgba.len(), PALETTE_SIZE * 4);
+        assert_eq!(&rgba[..4], &[0, 0, 0, 255]);
+        assert_eq!(&rgba[rgba.len() - 4..], &[255, 255, 255, 255]);
+    }
+}
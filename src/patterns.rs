@@ -0,0 +1,208 @@
+// Seed patterns for the initial state. The creature matrices are stored top row first,
+// the way they are usually published, and flipped when placed into the bottom-up grid.
+
+use crate::cpu::Grid;
+use crate::rle;
+
+// patterns that can be looked up by name, for stamping into a running world
+pub const LIBRARY: [&str; 10] = [
+    "glider",
+    "lwss",
+    "mwss",
+    "hwss",
+    "r_pentomino",
+    "acorn",
+    "diehard",
+    "gosper_glider_gun",
+    "orbium",
+    "geminium",
+];
+
+pub fn from_name(name: &str) -> Option<Vec<Vec<f64>>> {
+    let body = match name {
+        "glider" => "bo$2bo$3o!",
+        "lwss" => "bo2bo$o$o3bo$4o!",
+        "mwss" => "3bo$bo3bo$o$o4bo$5o!",
+        "hwss" => "3b2o$bo4bo$o$o5bo$6o!",
+        "r_pentomino" => "b2o$2o$bo!",
+        "acorn" => "bo$3bo$2o2b3o!",
+        "diehard" => "6bo$2o$bo3b3o!",
+        "gosper_glider_gun" => {
+            "24bo$22bobo$12b2o6b2o12b2o$11bo3bo4b2o12b2o$2o8bo5bo3b2o$2o8bo3bob2o4bobo$10bo5bo7bo$11bo3bo$12b2o!"
+        }
+        "orbium" => return Some(orbium()),
+        "geminium" => return Some(geminium()),
+        _ => return None,
+    };
+    let cells = rle::parse_body(body, rle::MAX_SIZE, rle::MAX_SIZE).ok()?;
+    let width = cells.iter().map(Vec::len).max().unwrap_or(0);
+    let pattern = cells
+        .iter()
+        .map(|row| (0..width).map(|x| if row.get(x).is_some_and(|&state| state > 0) { 1.0 } else { 0.0 }).collect())
+        .collect();
+    Some(pattern)
+}
+
+pub fn orbium() -> Vec<Vec<f64>> {
+    vec![vec![0.0,0.0,0.0,0.0,0.0,0.0,0.1,0.14,0.1,0.0,0.0,0.03,0.03,0.0,0.0,0.3,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.08,0.24,0.3,0.3,0.18,0.14,0.15,0.16,0.15,0.09,0.2,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.15,0.34,0.44,0.46,0.38,0.18,0.14,0.11,0.13,0.19,0.18,0.45,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.06,0.13,0.39,0.5,0.5,0.37,0.06,0.0,0.0,0.0,0.02,0.16,0.68,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.11,0.17,0.17,0.33,0.4,0.38,0.28,0.14,0.0,0.0,0.0,0.0,0.0,0.18,0.42,0.0,0.0], vec![0.0,0.0,0.09,0.18,0.13,0.06,0.08,0.26,0.32,0.32,0.27,0.0,0.0,0.0,0.0,0.0,0.0,0.82,0.0,0.0], vec![0.27,0.0,0.16,0.12,0.0,0.0,0.0,0.25,0.38,0.44,0.45,0.34,0.0,0.0,0.0,0.0,0.0,0.22,0.17,0.0], vec![0.0,0.07,0.2,0.02,0.0,0.0,0.0,0.31,0.48,0.57,0.6,0.57,0.0,0.0,0.0,0.0,0.0,0.0,0.49,0.0], vec![0.0,0.59,0.19,0.0,0.0,0.0,0.0,0.2,0.57,0.69,0.76,0.76,0.49,0.0,0.0,0.0,0.0,0.0,0.36,0.0], vec![0.0,0.58,0.19,0.0,0.0,0.0,0.0,0.0,0.67,0.83,0.9,0.92,0.87,0.12,0.0,0.0,0.0,0.0,0.22,0.07], vec![0.0,0.0,0.46,0.0,0.0,0.0,0.0,0.0,0.7,0.93,1.0,1.0,1.0,0.61,0.0,0.0,0.0,0.0,0.18,0.11], vec![0.0,0.0,0.82,0.0,0.0,0.0,0.0,0.0,0.47,1.0,1.0,0.98,1.0,0.96,0.27,0.0,0.0,0.0,0.19,0.1], vec![0.0,0.0,0.46,0.0,0.0,0.0,0.0,0.0,0.25,1.0,1.0,0.84,0.92,0.97,0.54,0.14,0.04,0.1,0.21,0.05], vec![0.0,0.0,0.0,0.4,0.0,0.0,0.0,0.0,0.09,0.8,1.0,0.82,0.8,0.85,0.63,0.31,0.18,0.19,0.2,0.01], vec![0.0,0.0,0.0,0.36,0.1,0.0,0.0,0.0,0.05,0.54,0.86,0.79,0.74,0.72,0.6,0.39,0.28,0.24,0.13,0.0], vec![0.0,0.0,0.0,0.01,0.3,0.07,0.0,0.0,0.08,0.36,0.64,0.7,0.64,0.6,0.51,0.39,0.29,0.19,0.04,0.0], vec![0.0,0.0,0.0,0.0,0.1,0.24,0.14,0.1,0.15,0.29,0.45,0.53,0.52,0.46,0.4,0.31,0.21,0.08,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.08,0.21,0.21,0.22,0.29,0.36,0.39,0.37,0.33,0.26,0.18,0.09,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.03,0.13,0.19,0.22,0.24,0.24,0.23,0.18,0.13,0.05,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.02,0.06,0.08,0.09,0.07,0.05,0.01,0.0,0.0,0.0,0.0,0.0]]
+}
+
+pub fn geminium() -> Vec<Vec<f64>> {
+    vec![vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.01,0.02,0.03,0.04,0.04,0.04,0.03,0.02,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.04,0.1,0.16,0.2,0.23,0.25,0.24,0.21,0.18,0.14,0.1,0.07,0.03,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.01,0.09,0.2,0.33,0.44,0.52,0.56,0.58,0.55,0.51,0.44,0.37,0.3,0.23,0.16,0.08,0.01,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.13,0.29,0.45,0.6,0.75,0.85,0.9,0.91,0.88,0.82,0.74,0.64,0.55,0.46,0.36,0.25,0.12,0.03,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.14,0.38,0.6,0.78,0.93,1.0,1.0,1.0,1.0,1.0,1.0,0.99,0.89,0.78,0.67,0.56,0.44,0.3,0.15,0.04,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.08,0.39,0.74,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.98,0.85,0.74,0.62,0.47,0.3,0.14,0.03,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.32,0.76,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.88,0.75,0.61,0.45,0.27,0.11,0.01,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.35,0.83,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.88,0.73,0.57,0.38,0.19,0.05,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.5,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.99,1.0,1.0,1.0,1.0,0.99,1.0,1.0,1.0,1.0,1.0,1.0,0.85,0.67,0.47,0.27,0.11,0.01], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.55,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.93,0.83,0.79,0.84,0.88,0.89,0.9,0.93,0.98,1.0,1.0,1.0,1.0,0.98,0.79,0.57,0.34,0.15,0.03], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.47,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.9,0.72,0.54,0.44,0.48,0.6,0.7,0.76,0.82,0.91,0.99,1.0,1.0,1.0,1.0,0.91,0.67,0.41,0.19,0.05], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.27,0.99,1.0,1.0,1.0,1.0,0.9,0.71,0.65,0.55,0.38,0.2,0.14,0.21,0.36,0.52,0.64,0.73,0.84,0.95,1.0,1.0,1.0,1.0,1.0,0.78,0.49,0.24,0.07], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.14,0.63,0.96,1.0,1.0,1.0,0.84,0.17,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.13,0.35,0.51,0.64,0.77,0.91,0.99,1.0,1.0,1.0,1.0,0.88,0.58,0.29,0.09], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.07,0.38,0.72,0.95,1.0,1.0,1.0,0.22,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.11,0.33,0.5,0.67,0.86,0.99,1.0,1.0,1.0,1.0,0.95,0.64,0.33,0.1], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.32,0.49,0.71,0.93,1.0,1.0,1.0,0.56,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.1,0.31,0.52,0.79,0.98,1.0,1.0,1.0,1.0,0.98,0.67,0.35,0.11], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.01,0.6,0.83,0.98,1.0,1.0,0.68,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.15,0.38,0.71,0.97,1.0,1.0,1.0,1.0,0.97,0.67,0.35,0.11], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.51,0.96,1.0,1.0,0.18,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.09,0.34,0.68,0.95,1.0,1.0,1.0,1.0,0.91,0.61,0.32,0.1], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.13,0.56,0.99,1.0,1.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.17,0.45,0.76,0.96,1.0,1.0,1.0,1.0,0.82,0.52,0.26,0.07], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.33,0.7,0.94,1.0,1.0,0.44,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.33,0.68,0.91,0.99,1.0,1.0,1.0,1.0,0.71,0.42,0.19,0.03], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.53,0.89,1.0,1.0,1.0,0.8,0.43,0.04,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.47,0.86,1.0,1.0,1.0,1.0,1.0,0.95,0.58,0.32,0.12,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.77,0.99,1.0,0.97,0.58,0.41,0.33,0.18,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.54,0.95,1.0,1.0,1.0,1.0,1.0,0.8,0.44,0.21,0.06,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.39,0.83,1.0,1.0,0.55,0.11,0.05,0.15,0.22,0.06,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.58,0.99,1.0,1.0,1.0,1.0,1.0,0.59,0.29,0.11,0.01,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.04,0.55,0.81,0.86,0.97,1.0,1.0,0.5,0.0,0.0,0.01,0.09,0.03,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.26,0.78,1.0,1.0,1.0,1.0,1.0,0.66,0.35,0.13,0.03,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.33,1.0,1.0,1.0,1.0,1.0,1.0,0.93,0.11,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.23,0.73,0.95,1.0,1.0,1.0,1.0,1.0,0.62,0.35,0.12,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.51,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.72,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.56,0.25,0.09,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.12,0.38,1.0,1.0,1.0,0.66,0.08,0.55,1.0,1.0,1.0,0.03,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.35,1.0,1.0,1.0,1.0,1.0,1.0,0.67,0.12,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.6,1.0,1.0,1.0,1.0,1.0,1.0,0.49,0.0,0.0,0.87,1.0,0.88,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,1.0,1.0,1.0,1.0,1.0,1.0,0.7,0.07,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.04,0.21,0.48,1.0,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.0,0.0,0.04,0.42,0.26,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.12,0.21,0.34,0.58,1.0,1.0,1.0,0.99,0.97,0.99,0.46,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.5,1.0,1.0,1.0,1.0,0.96,0.0,0.31,1.0,1.0,1.0,0.53,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.2,0.21,0.0,0.0,0.0,0.27,1.0,1.0,1.0,1.0,1.0,1.0,0.87,0.52,0.01,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.84,1.0,1.0,1.0,1.0,1.0,0.0,0.0,0.0,0.83,1.0,1.0,0.52,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.26,0.82,0.59,0.02,0.0,0.0,0.46,1.0,1.0,1.0,1.0,1.0,0.9,0.55,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.39,0.99,1.0,1.0,1.0,1.0,0.78,0.04,0.0,0.0,0.0,0.93,0.92,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.69,1.0,1.0,0.36,0.0,0.0,1.0,1.0,0.65,0.66,0.97,0.87,0.54,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.55,0.75,0.59,0.74,1.0,1.0,0.0,0.0,0.75,0.71,0.18,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.29,0.0,0.0,0.45,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.47,0.39,0.71,0.25,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.69,0.81,0.8,0.92,1.0,0.13,0.0,0.0,0.13,0.94,0.58,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,1.0,1.0,0.34,0.0,0.04,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.24,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.63,0.85,0.9,0.98,1.0,0.09,0.0,0.0,0.02,1.0,0.64,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.59,1.0,1.0,0.84,0.0,0.0,1.0,1.0,1.0,1.0,1.0,1.0,0.64,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.64,0.65,0.67,1.0,1.0,0.21,0.01,0.0,0.04,0.02,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.69,1.0,1.0,1.0,0.29,0.37,1.0,1.0,0.6,0.63,1.0,0.84,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.44,0.73,0.73,0.85,1.0,0.97,0.23,0.05,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.06,0.0,0.0,0.0,0.97,1.0,1.0,1.0,1.0,1.0,1.0,0.33,0.24,0.67,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.12,0.55,0.9,0.9,1.0,1.0,1.0,0.43,0.04,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.31,0.54,0.0,0.0,0.0,0.88,1.0,1.0,1.0,1.0,1.0,1.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.29,0.71,1.0,1.0,1.0,1.0,0.79,0.28,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.4,0.77,0.54,0.0,0.0,0.87,1.0,1.0,1.0,1.0,1.0,0.31,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.16,0.27,0.41,0.72,0.99,1.0,1.0,0.82,0.42,0.09,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.1,0.55,0.58,0.58,0.77,0.99,1.0,1.0,1.0,1.0,0.63,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.31,0.48,0.45,0.46,0.63,0.88,1.0,0.83,0.59,0.28,0.06,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.32,0.7,0.95,1.0,1.0,1.0,1.0,0.7,0.58,0.12,0.04,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.23,0.54,0.53,0.48,0.57,0.59,0.65,0.63,0.55,0.35,0.13,0.03,0.02,0.09,0.74,1.0,0.09,0.0,0.0,0.0,0.32,0.86,1.0,1.0,1.0,1.0,0.57,0.44,0.31,0.16,0.01,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.31,0.45,0.31,0.18,0.28,0.39,0.47,0.54,0.5,0.35,0.2,0.16,0.28,0.75,1.0,0.42,0.01,0.0,0.0,0.6,1.0,1.0,1.0,1.0,0.51,0.29,0.09,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.14,0.3,0.4,0.54,0.71,0.74,0.65,0.49,0.35,0.27,0.47,0.6,0.6,0.72,0.98,1.0,1.0,1.0,1.0,0.65,0.33,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.06,0.33,0.53,0.69,0.94,0.99,1.0,0.84,0.41,0.16,0.15,0.96,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.73,0.13,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.42,0.86,0.98,0.98,0.99,1.0,0.94,0.63,0.32,0.62,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.65,0.23,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.07,0.62,0.95,1.0,1.0,0.99,0.98,0.99,1.0,1.0,1.0,1.0,1.0,1.0,1.0,0.98,0.14,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.03,0.46,0.89,1.0,1.0,0.97,0.83,0.75,0.81,0.94,1.0,1.0,1.0,1.0,0.99,0.03,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.14,0.57,0.88,0.93,0.81,0.58,0.45,0.48,0.64,0.86,0.97,0.99,0.99,0.42,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.23,0.45,0.47,0.39,0.29,0.19,0.2,0.46,0.28,0.03,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.08,0.22,0.24,0.15,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0], vec![0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.07,0.22,0.14,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0,0.0]]
+}
+
+// writes `pattern` with its top-left cell at (x, y), counted from the top-left of the world
+// as it is displayed; cells falling outside the grid are dropped
+pub fn place(grid: &mut Grid, pattern: &[Vec<f64>], x: i32, y: i32) {
+    let width = grid.width() as i32;
+    let height = grid.height() as i32;
+    for (row, values) in pattern.iter().enumerate() {
+        let grid_y = height - 1 - (y + row as i32);
+        for (column, value) in values.iter().enumerate() {
+            let grid_x = x + column as i32;
+            if grid_x >= 0 && grid_y >= 0 && grid_x < width && grid_y < height {
+                grid.set(grid_x as usize, grid_y as usize, *value as f32);
+            }
+        }
+    }
+}
+
+pub fn place_centered(grid: &mut Grid, pattern: &[Vec<f64>]) {
+    let width = grid.width() as i32;
+    let height = grid.height() as i32;
+    let (pattern_width, pattern_height) = size(pattern);
+    let center_x = (width / 2) - pattern_width / 2;
+    let center_y = (height / 2) - pattern_height / 2;
+    place(grid, pattern, center_x, height - center_y - pattern_height);
+}
+
+// Like `place`, but with the pattern centred on (x, y) and wrapping around the edges, since
+// the world is a torus. The web build writes the same cells with stamp_pieces.
+pub fn stamp(grid: &mut Grid, pattern: &[Vec<f64>], x: i32, y: i32) {
+    let width = grid.width() as i32;
+    let height = grid.height() as i32;
+    let (pattern_width, pattern_height) = size(pattern);
+    for (row, values) in pattern.iter().enumerate() {
+        let grid_y = (height - 1 - (y - pattern_height / 2 + row as i32)).rem_euclid(height);
+        for (column, value) in values.iter().enumerate() {
+            let grid_x = (x - pattern_width / 2 + column as i32).rem_euclid(width);
+            grid.set(grid_x as usize, grid_y as usize, *value as f32);
+        }
+    }
+}
+
+// What `stamp` writes, as the pieces the pattern is cut into where it wraps around the edges:
+// the bottom-left corner of each piece in grid coordinates and its cells. Lets a running
+// world be stamped without touching the rest of it. The pattern is taken as rectangular, as
+// `oriented` makes it; where it is larger than the world, its last rows and columns win.
+pub fn stamp_pieces(width: usize, height: usize, pattern: &[Vec<f64>], x: i32, y: i32) -> Vec<(usize, usize, Grid)> {
+    let (pattern_width, pattern_height) = size(pattern);
+    let (columns, rows) = (pattern_width.min(width as i32), pattern_height.min(height as i32));
+    let (skipped_columns, skipped_rows) = (pattern_width - columns, pattern_height - rows);
+    // the column and row of the first cell kept, from the left and from the top
+    let left = x - pattern_width / 2 + skipped_columns;
+    let top = y - pattern_height / 2 + skipped_rows;
+
+    // (offset in the kept cells, start in the world, length) of each side of the wrap
+    let spans = |start: i32, length: i32, size: i32| {
+        let start = start.rem_euclid(size);
+        let first = length.min(size - start);
+        let mut spans = vec![(0, start, first)];
+        if first < length {
+            spans.push((first, 0, length - first));
+        }
+        spans
+    };
+
+    let mut pieces = Vec::new();
+    if columns == 0 || rows == 0 {
+        return pieces;
+    }
+    for (column, grid_x, piece_width) in spans(left, columns, width as i32) {
+        for (row, grid_row, piece_height) in spans(top, rows, height as i32) {
+            let mut piece = Grid::new(piece_width as usize, piece_height as usize);
+            for piece_row in 0..piece_height {
+                let values = &pattern[(skipped_rows + row + piece_row) as usize];
+                for piece_column in 0..piece_width {
+                    let value = values.get((skipped_columns + column + piece_column) as usize).copied().unwrap_or(0.0);
+                    piece.set(piece_column as usize, (piece_height - 1 - piece_row) as usize, value as f32);
+                }
+            }
+            // rows count from the top, the grid from the bottom
+            let bottom = height as i32 - grid_row - piece_height;
+            pieces.push((grid_x as usize, bottom as usize, piece));
+        }
+    }
+    pieces
+}
+
+// quarter turns are clockwise as displayed; mirroring flips left and right before turning
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Orientation {
+    pub quarter_turns: i32,
+    pub mirrored: bool,
+}
+
+pub fn oriented(pattern: &[Vec<f64>], orientation: Orientation) -> Vec<Vec<f64>> {
+    let (width, height) = size(pattern);
+    let (width, height) = (width as usize, height as usize);
+    let cell = |x: usize, y: usize| {
+        let x = if orientation.mirrored { width - 1 - x } else { x };
+        pattern[y].get(x).copied().unwrap_or(0.0)
+    };
+    match orientation.quarter_turns.rem_euclid(4) {
+        0 => (0..height).map(|y| (0..width).map(|x| cell(x, y)).collect()).collect(),
+        1 => (0..width).map(|y| (0..height).map(|x| cell(y, height - 1 - x)).collect()).collect(),
+        2 => (0..height).map(|y| (0..width).map(|x| cell(width - 1 - x, height - 1 - y)).collect()).collect(),
+        _ => (0..width).map(|y| (0..height).map(|x| cell(width - 1 - y, x)).collect()).collect(),
+    }
+}
+
+// width and height of a pattern, taking the first row as the width
+fn size(pattern: &[Vec<f64>]) -> (i32, i32) {
+    (pattern.first().map_or(0, |row| row.len()) as i32, pattern.len() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the pieces written into `grid` one by one, as apply_stamps uploads them
+    fn stamp_by_pieces(grid: &mut Grid, pattern: &[Vec<f64>], x: i32, y: i32) {
+        for (left, bottom, piece) in stamp_pieces(grid.width(), grid.height(), pattern, x, y) {
+            for piece_y in 0..piece.height() {
+                for piece_x in 0..piece.width() {
+                    grid.set(left + piece_x, bottom + piece_y, piece.get(piece_x, piece_y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stamp_pieces_write_what_stamp_does() {
+        let glider = from_name("glider").unwrap();
+        let gun = from_name("gosper_glider_gun").unwrap();
+        let orbium = orbium();
+        // inside, across one edge, across a corner, wider and taller than the world
+        for (pattern, x, y) in [(&glider, 5, 5), (&glider, 0, 4), (&glider, 11, 0), (&gun, 3, 2), (&orbium, 6, 4)] {
+            let mut background = Grid::new(12, 9);
+            for cell in 0..12 * 9 {
+                background.set(cell % 12, cell / 12, (cell % 5) as f32 / 4.0);
+            }
+            let mut expected = background.clone();
+            stamp(&mut expected, pattern, x, y);
+            let mut stamped = background;
+            stamp_by_pieces(&mut stamped, pattern, x, y);
+            assert_eq!(stamped, expected, "at {}, {}", x, y);
+        }
+    }
+
+    #[test]
+    fn stamp_pieces_split_at_the_edges() {
+        let glider = from_name("glider").unwrap();
+        assert_eq!(stamp_pieces(12, 9, &glider, 5, 5).len(), 1);
+        assert_eq!(stamp_pieces(12, 9, &glider, 0, 4).len(), 2);
+        assert_eq!(stamp_pieces(12, 9, &glider, 11, 0).len(), 4);
+        assert!(stamp_pieces(12, 9, &[], 5, 5).is_empty());
+    }
+}
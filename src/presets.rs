@@ -0,0 +1,129 @@
+// Named rule presets: a rule with its parameters, the world it starts from and the world size
+// it is meant to be watched at. index.html, the CLI and the exports address them by id.
+
+use crate::generators::Generator;
+use crate::rules::{GrowthFunction, Kernel, KernelCore, LeniaParams, LifeLikeParams, PrimordiaParams, Rule};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Preset {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub rule: Rule,
+    pub start: Generator,
+    pub world: (usize, usize),
+}
+
+const WORLD: (usize, usize) = (512, 512);
+// small enough for the Lenia creatures to fill the screen
+const LENIA_WORLD: (usize, usize) = (256, 256);
+
+impl Preset {
+    pub fn all() -> Vec<Self> {
+        let lenia = |id, name, kernel, time_step, mu, sigma, start| Self {
+            id,
+            name,
+            rule: Rule::Lenia(LeniaParams {
+                kernel,
+                time_step,
+                mu,
+                sigma,
+                // the bell the Lenia shader has always used
+                growth: GrowthFunction::Gaussian,
+            }),
+            start,
+            world: LENIA_WORLD,
+        };
+        vec![
+            Self {
+                id: "gol",
+                name: "Game of Life",
+                rule: Rule::LifeLike(LifeLikeParams::default()),
+                start: Generator::Noise,
+                world: WORLD,
+            },
+            Self {
+                id: "generations",
+                name: "Brian's Brain",
+                rule: Rule::Generations {
+                    params: LifeLikeParams::brians_brain(),
+                    states: 3,
+                },
+                start: Generator::Noise,
+                world: WORLD,
+            },
+            Self {
+                id: "ltl",
+                name: "Larger than Life (Bosco)",
+                rule: Rule::larger_than_life(),
+                start: Generator::Noise,
+                world: WORLD,
+            },
+            Self {
+                id: "primordia",
+                name: "Primordia",
+                rule: Rule::Primordia {
+                    params: PrimordiaParams::default(),
+                    states: 12,
+                },
+                start: Generator::Noise,
+                world: WORLD,
+            },
+            Self {
+                id: "primordia_time",
+                name: "Primordia Time",
+                rule: Rule::primordia_time(),
+                start: Generator::Noise,
+                world: WORLD,
+            },
+            lenia("lenia", "Lenia, square kernel", Kernel::square(5), 10.0, 0.15, 0.015, Generator::Noise),
+            lenia(
+                "lenia_annulus",
+                "Lenia, ring kernel",
+                Kernel::annulus(5, 1.5, 5.1),
+                10.0,
+                0.15,
+                0.015,
+                Generator::Noise,
+            ),
+            lenia(
+                "lenia_smooth",
+                "Lenia, smooth ring kernel",
+                Kernel::generate(10, &[1.0], KernelCore::Gaussian),
+                10.0,
+                0.15,
+                0.015,
+                Generator::Noise,
+            ),
+            lenia(
+                "orbium",
+                "Orbium",
+                Kernel::generate(13, &[1.0], KernelCore::Gaussian),
+                10.0,
+                0.15,
+                0.015,
+                Generator::Pattern("orbium"),
+            ),
+            lenia(
+                "geminium",
+                "Geminium",
+                Kernel::generate(18, &[0.5, 1.0, 2.0 / 3.0], KernelCore::Gaussian),
+                5.0,
+                0.26,
+                0.036,
+                Generator::Pattern("geminium"),
+            ),
+        ]
+    }
+
+    pub fn find(id: &str) -> Option<Self> {
+        Self::all().into_iter().find(|preset| preset.id == id)
+    }
+
+    // the preset's rule, with `states` replacing the default of the Primordia rule
+    pub fn rule(&self, states: i32) -> Result<Rule, String> {
+        match &self.rule {
+            Rule::Primordia { params, .. } => Rule::primordia(params.clone(), states),
+            rule => Ok(rule.clone()),
+        }
+    }
+}
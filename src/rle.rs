@@ -0,0 +1,198 @@
+// Reader for the run-length encoded pattern format used by Golly and the LifeWiki
+// collections. Multi-state tags (`.`, `A`-`X`, `pA`-`yO`) are accepted alongside `b`/`o`.
+
+// largest pattern accepted, in cells either way: the largest world index.html offers
+pub const MAX_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RlePattern {
+    pub width: usize,
+    pub height: usize,
+    pub rule: Option<String>,
+    // top row first, one state per cell (0 is dead)
+    pub cells: Vec<Vec<u8>>,
+}
+
+impl RlePattern {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut width = None;
+        let mut height = None;
+        let mut rule = None;
+        let mut body = String::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if body.is_empty() && width.is_none() && line.starts_with('x') {
+                // the rule runs to the end of the line, since HROT rules and Golly's bounded
+                // grids (B3/S23:T10,10) have commas of their own
+                let (fields, rule_field) = match line.split_once("rule") {
+                    Some((fields, rest)) => (fields, Some(rest)),
+                    None => (line, None),
+                };
+                if let Some(rest) = rule_field {
+                    let value = rest
+                        .trim_start()
+                        .strip_prefix('=')
+                        .ok_or_else(|| format!("Invalid RLE header field: rule{}", rest))?;
+                    rule = Some(value.trim().to_string());
+                }
+                for field in fields.split(',').map(str::trim).filter(|field| !field.is_empty()) {
+                    let (key, value) = field
+                        .split_once('=')
+                        .ok_or_else(|| format!("Invalid RLE header field: {}", field))?;
+                    let value = value.trim();
+                    match key.trim() {
+                        "x" => width = Some(parse_size(value)?),
+                        "y" => height = Some(parse_size(value)?),
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+            body.push_str(line);
+            if line.contains('!') {
+                break;
+            }
+        }
+
+        let width = width.ok_or_else(|| String::from("RLE header line missing"))?;
+        let height = height.ok_or_else(|| String::from("RLE header line missing"))?;
+        let mut cells = parse_body(&body, width, height)?;
+        cells.resize(height, Vec::new());
+        for row in &mut cells {
+            row.resize(width, 0);
+        }
+
+        Ok(Self {
+            width,
+            height,
+            rule,
+            cells,
+        })
+    }
+
+    // Cell values as the shaders see them for a rule of `states` states (see Rule::rle_states).
+    // With two every live state is 1.0; with more, state 1 is alive and the following ones are
+    // dying, so state k is the Generations level (states - k) / (states - 1).
+    pub fn to_pattern(&self, states: i32) -> Vec<Vec<f64>> {
+        let value = |state: u8| match (state, states) {
+            (0, _) => 0.0,
+            (_, ..=2) => 1.0,
+            (state, states) => (states - state as i32).max(0) as f64 / (states - 1) as f64,
+        };
+        self.cells.iter().map(|row| row.iter().map(|&state| value(state)).collect()).collect()
+    }
+}
+
+fn parse_size(value: &str) -> Result<usize, String> {
+    let size = value
+        .parse()
+        .map_err(|e| format!("Invalid RLE size {}: {}", value, e))?;
+    if size > MAX_SIZE {
+        return Err(format!("RLE size {} is larger than {}", size, MAX_SIZE));
+    }
+    Ok(size)
+}
+
+// Decodes the cell data after the header; rows are as long as their last live cell. A run
+// taking the pattern past `width` x `height` is an error before anything is allocated for it.
+pub fn parse_body(body: &str, width: usize, height: usize) -> Result<Vec<Vec<u8>>, String> {
+    let too_large = || format!("RLE body exceeds the {}x{} bounding box", width, height);
+    let mut cells = vec![Vec::new()];
+    // rows ended by $ but not started yet, so that trailing ones cost nothing
+    let mut blank_rows = 0usize;
+    let mut count = 0usize;
+    let mut prefix = None;
+    for tag in body.chars() {
+        let run = count.max(1);
+        match tag {
+            '0'..='9' => {
+                count = count
+                    .checked_mul(10)
+                    .and_then(|count| count.checked_add(tag.to_digit(10).unwrap() as usize))
+                    .ok_or_else(too_large)?;
+                continue;
+            }
+            'p'..='y' => {
+                prefix = Some(tag as u8 - b'p' + 1);
+                continue;
+            }
+            '$' => blank_rows = blank_rows.checked_add(run).ok_or_else(too_large)?,
+            '!' => break,
+            _ if tag.is_whitespace() => continue,
+            _ => {
+                let state = match (tag, prefix.take()) {
+                    ('b' | '.', None) => 0,
+                    ('o', None) => 1,
+                    ('A'..='X', prefix) => {
+                        prefix.unwrap_or(0) as usize * 24 + (tag as u8 - b'A') as usize + 1
+                    }
+                    _ => return Err(format!("Invalid RLE tag: {}", tag)),
+                };
+                if state > u8::MAX as usize {
+                    return Err(format!("RLE state {} out of range", state));
+                }
+                if cells.len().saturating_add(blank_rows) > height {
+                    return Err(too_large());
+                }
+                cells.extend((0..blank_rows).map(|_| Vec::new()));
+                blank_rows = 0;
+                let row = cells.last_mut().unwrap();
+                let length = row.len().checked_add(run).filter(|&length| length <= width).ok_or_else(too_large)?;
+                row.resize(length, state as u8);
+            }
+        }
+        count = 0;
+    }
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rule;
+
+    #[test]
+    fn reads_a_glider() {
+        let pattern = RlePattern::parse("#N Glider\nx = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n").unwrap();
+        assert_eq!(pattern.rule.as_deref(), Some("B3/S23"));
+        assert_eq!(pattern.cells, vec![vec![0, 1, 0], vec![0, 0, 1], vec![1, 1, 1]]);
+    }
+
+    #[test]
+    fn rule_runs_to_the_end_of_the_header() {
+        let pattern = RlePattern::parse("x = 3, y = 1, rule = R5,C0,M1,S34..58,B34..45,NM\n3o!").unwrap();
+        assert_eq!((pattern.width, pattern.height), (3, 1));
+        assert_eq!(pattern.rule.as_deref(), Some("R5,C0,M1,S34..58,B34..45,NM"));
+        let pattern = RlePattern::parse("x = 3, y = 1, rule = B3/S23:T10,10\n3o!").unwrap();
+        assert_eq!(pattern.rule.as_deref(), Some("B3/S23:T10,10"));
+        assert_eq!(Rule::parse("B3/S23:T10,10"), Rule::parse("B3/S23"));
+        assert!(RlePattern::parse("x = 3, y = 1, rule R5\n3o!").is_err());
+    }
+
+    #[test]
+    fn generations_states_count_down() {
+        let pattern = RlePattern::parse("x = 4, y = 1, rule = B2/S/C4
+.ABC!").unwrap();
+        assert_eq!(pattern.cells, vec![vec![0, 1, 2, 3]]);
+        assert_eq!(pattern.to_pattern(4), vec![vec![0.0, 1.0, 2.0 / 3.0, 1.0 / 3.0]]);
+        assert_eq!(pattern.to_pattern(2), vec![vec![0.0, 1.0, 1.0, 1.0]]);
+    }
+
+    #[test]
+    fn trailing_row_ends_are_ignored() {
+        let pattern = RlePattern::parse("x = 2, y = 2\no$o5$!").unwrap();
+        assert_eq!(pattern.cells, vec![vec![1, 0], vec![1, 0]]);
+    }
+
+    #[test]
+    fn runs_outside_the_bounding_box_are_rejected() {
+        for body in ["99999999999999999999o!", "4o!", "o$o$o$o!", "o99999999$o!", "99999999999b!"] {
+            assert!(RlePattern::parse(&format!("x = 3, y = 3\n{}", body)).is_err(), "{}", body);
+        }
+        assert!(RlePattern::parse("x = 100000, y = 1\no!").is_err());
+    }
+}
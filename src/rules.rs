@@ -0,0 +1,490 @@
+// Rule definitions mirroring the constants baked into the fragment shaders in `shaders/`.
+// Both the CPU engine and the WebGL simulation are parameterised from here.
+
+// largest neighbourhood or kernel radius, as HROT tools allow; the shaders visit (2R+1)^2
+// cells for every cell
+pub const MAX_RADIUS: i32 = 500;
+
+// outer-totalistic Life-like rule, indexed by the number of live Moore neighbours
+#[derive(Debug, Clone, PartialEq)]
+pub struct LifeLikeParams {
+    pub birth: [bool; 9],
+    pub survival: [bool; 9],
+}
+
+impl Default for LifeLikeParams {
+    // B3/S23, what the Game of Life shader used to hard-code
+    fn default() -> Self {
+        let mut birth = [false; 9];
+        let mut survival = [false; 9];
+        birth[3] = true;
+        survival[2] = true;
+        survival[3] = true;
+        Self { birth, survival }
+    }
+}
+
+impl LifeLikeParams {
+    // B2/S, the counts of Brian's Brain
+    pub fn brians_brain() -> Self {
+        let mut birth = [false; 9];
+        birth[2] = true;
+        Self {
+            birth,
+            survival: [false; 9],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Neighbourhood {
+    Moore,
+    VonNeumann,
+    Circular,
+}
+
+impl Neighbourhood {
+    pub fn contains(self, dx: i32, dy: i32, radius: i32) -> bool {
+        match self {
+            Neighbourhood::Moore => dx.abs() <= radius && dy.abs() <= radius,
+            Neighbourhood::VonNeumann => dx.abs() + dy.abs() <= radius,
+            Neighbourhood::Circular => dx * dx + dy * dy <= radius * radius,
+        }
+    }
+
+    // number of cells in the neighbourhood, centre included
+    pub fn size(self, radius: i32) -> i32 {
+        let mut size = 0;
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                size += self.contains(dx, dy, radius) as i32;
+            }
+        }
+        size
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LtlParams {
+    pub radius: i32,
+    pub neighbourhood: Neighbourhood,
+    pub include_center: bool,
+    // inclusive ranges of neighbour counts
+    pub birth: Vec<(i32, i32)>,
+    pub survival: Vec<(i32, i32)>,
+}
+
+impl Default for LtlParams {
+    // R5,C0,M1,S34..58,B34..45,NM, what the Larger than Life shader used to hard-code
+    fn default() -> Self {
+        Self {
+            radius: 5,
+            neighbourhood: Neighbourhood::Moore,
+            include_center: true,
+            birth: vec![(34, 45)],
+            survival: vec![(34, 58)],
+        }
+    }
+}
+
+impl LtlParams {
+    pub fn max_count(&self) -> i32 {
+        self.neighbourhood.size(self.radius) - (!self.include_center) as i32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimordiaParams {
+    pub radius: i32,
+    pub birth: (f32, f32),
+    pub survival: (f32, f32),
+}
+
+impl Default for PrimordiaParams {
+    // the Primordia shaders used to hard-code these
+    fn default() -> Self {
+        Self {
+            radius: 1,
+            birth: (0.20, 0.25),
+            survival: (0.18, 0.33),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KernelCore {
+    Polynomial,
+    Exponential,
+    Step,
+    Staircase,
+    Gaussian,
+}
+
+impl KernelCore {
+    // `kn` in the Lenia animal library, 1-based
+    pub fn from_index(kn: i64) -> Option<Self> {
+        match kn {
+            1 => Some(KernelCore::Polynomial),
+            2 => Some(KernelCore::Exponential),
+            3 => Some(KernelCore::Step),
+            4 => Some(KernelCore::Staircase),
+            _ => None,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "polynomial" => Some(KernelCore::Polynomial),
+            "exponential" => Some(KernelCore::Exponential),
+            "step" => Some(KernelCore::Step),
+            "staircase" => Some(KernelCore::Staircase),
+            "gaussian" => Some(KernelCore::Gaussian),
+            _ => None,
+        }
+    }
+
+    fn value(self, r: f32) -> f32 {
+        match self {
+            KernelCore::Polynomial => (4.0 * r * (1.0 - r)).powi(4),
+            KernelCore::Exponential if r > 0.0 && r < 1.0 => (4.0 - 1.0 / (r * (1.0 - r))).exp(),
+            KernelCore::Exponential => 0.0,
+            KernelCore::Step => ((0.25..=0.75).contains(&r)) as i32 as f32,
+            KernelCore::Staircase if r < 0.25 => 0.5,
+            KernelCore::Staircase => (r <= 0.75) as i32 as f32,
+            KernelCore::Gaussian => (-((r - 0.5) / 0.15).powi(2) / 2.0).exp(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kernel {
+    pub radius: i32,
+    pub size: i32,
+    pub weights: Vec<f32>,
+}
+
+impl Kernel {
+    // concentric rings with relative heights `peaks`, each shaped by `core`
+    pub fn generate(radius: i32, peaks: &[f32], core: KernelCore) -> Self {
+        let rings = peaks.len() as f32;
+        Self::from_distance(radius, |distance| {
+            let distance = distance / radius as f32 * rings;
+            if distance < rings {
+                peaks[distance as usize] * core.value(distance.fract())
+            } else {
+                0.0
+            }
+        })
+    }
+
+    // every cell of the (2R+1)^2 square except the centre
+    pub fn square(radius: i32) -> Self {
+        Self::from_distance(radius, |distance| (distance > 0.0) as i32 as f32)
+    }
+
+    // flat weights for the cells with inner < distance <= outer
+    pub fn annulus(radius: i32, inner: f32, outer: f32) -> Self {
+        Self::from_distance(radius, |distance| (distance > inner && distance <= outer) as i32 as f32)
+    }
+
+    // weights normalised to sum to 1, so U stays in 0..1
+    fn from_distance(radius: i32, weight: impl Fn(f32) -> f32) -> Self {
+        let size = 2 * radius + 1;
+        let mut weights = Vec::with_capacity((size * size) as usize);
+        for y in -radius..=radius {
+            for x in -radius..=radius {
+                weights.push(weight(((x * x + y * y) as f32).sqrt()));
+            }
+        }
+        let total: f32 = weights.iter().sum();
+        if total > 0.0 {
+            weights.iter_mut().for_each(|w| *w /= total);
+        }
+        Self { radius, size, weights }
+    }
+}
+
+// maps the neighbourhood sum U to a growth in -1..1 around mu, sigma wide
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GrowthFunction {
+    Polynomial,
+    Gaussian,
+    Step,
+}
+
+impl GrowthFunction {
+    // `gn` in the Lenia animal library, 1-based
+    pub fn from_index(gn: i64) -> Option<Self> {
+        match gn {
+            1 => Some(GrowthFunction::Polynomial),
+            2 => Some(GrowthFunction::Gaussian),
+            3 => Some(GrowthFunction::Step),
+            _ => None,
+        }
+    }
+
+    pub fn index(self) -> i64 {
+        self as i64 + 1
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "polynomial" => Some(GrowthFunction::Polynomial),
+            "gaussian" => Some(GrowthFunction::Gaussian),
+            "step" => Some(GrowthFunction::Step),
+            _ => None,
+        }
+    }
+
+    // the growth functions of shaders/growth, picked there by GROWTH
+    pub fn value(self, u: f32, mu: f32, sigma: f32) -> f32 {
+        let bump = match self {
+            GrowthFunction::Polynomial => (1.0 - (u - mu).powi(2) / (9.0 * sigma * sigma)).max(0.0).powi(4),
+            GrowthFunction::Gaussian => (-((u - mu) / sigma).powi(2) / 2.0).exp(),
+            GrowthFunction::Step => ((u - mu).abs() <= sigma) as i32 as f32,
+        };
+        bump * 2.0 - 1.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeniaParams {
+    pub kernel: Kernel,
+    pub time_step: f32,
+    pub mu: f32,
+    pub sigma: f32,
+    pub growth: GrowthFunction,
+}
+
+impl LeniaParams {
+    // T divides the growth and s is the width it is spread over, so both must be positive
+    pub fn new(kernel: Kernel, time_step: f32, mu: f32, sigma: f32, growth: GrowthFunction) -> Result<Self, String> {
+        if !(time_step > 0.0 && sigma > 0.0) {
+            return Err(format!("Lenia needs T and s above 0, got T={} s={}", time_step, sigma));
+        }
+        Ok(Self {
+            kernel,
+            time_step,
+            mu,
+            sigma,
+            growth,
+        })
+    }
+}
+
+fn first_range(ranges: &mut Vec<(i32, i32)>, value: i32) -> &mut (i32, i32) {
+    if ranges.is_empty() {
+        ranges.push((value, value));
+    }
+    &mut ranges[0]
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    LifeLike(LifeLikeParams),
+    // Life-like births and survivals, but a dying cell goes through states - 2 refractory states
+    Generations { params: LifeLikeParams, states: i32 },
+    LargerThanLife(LtlParams),
+    Primordia { params: PrimordiaParams, states: i32 },
+    PrimordiaTime { params: PrimordiaParams, time_step: f32 },
+    Lenia(LeniaParams),
+}
+
+impl Rule {
+    // "B3/S23"-style Life-like rules, "B2/S/C3"-style Generations rules or
+    // "R5,C0,S34-58,B34-45,NM"-style Larger than Life rules. Golly's bounded grid suffix, as in
+    // "B3/S23:T10,10", is dropped: the world is always a torus of its own size.
+    pub fn parse(rulestring: &str) -> Result<Self, String> {
+        let rulestring = rulestring.split(':').next().unwrap_or_default().trim();
+        if rulestring.starts_with(['R', 'r']) {
+            Ok(Rule::LargerThanLife(LtlParams::parse(rulestring)?))
+        } else if rulestring.matches('/').count() == 2 {
+            let (params, states) = LifeLikeParams::parse_generations(rulestring)?;
+            Rule::generations(params, states)
+        } else {
+            Ok(Rule::LifeLike(LifeLikeParams::parse(rulestring)?))
+        }
+    }
+
+    pub fn rulestring(&self) -> Option<String> {
+        match self {
+            Rule::LifeLike(params) => Some(params.to_rulestring()),
+            Rule::Generations { params, states } => Some(params.to_generations_rulestring(*states)),
+            Rule::LargerThanLife(params) => Some(params.to_rulestring()),
+            _ => None,
+        }
+    }
+
+    // rules whose cells are only dead or alive; a live Generations cell is 1.0 and it decays on
+    // its own
+    pub fn is_binary(&self) -> bool {
+        matches!(self, Rule::LifeLike(_) | Rule::Generations { .. } | Rule::LargerThanLife(_))
+    }
+
+    // the states an RLE pattern of this rule tells apart: Generations cells are dead, alive or
+    // one of the dying states, everything else is dead or alive
+    pub fn rle_states(&self) -> i32 {
+        match self {
+            Rule::Generations { states, .. } => *states,
+            _ => 2,
+        }
+    }
+
+    // birth and survival lookup tables indexed by neighbour count, for the binary rules
+    pub fn transition_table(&self) -> Option<(Vec<bool>, Vec<bool>)> {
+        match self {
+            Rule::LifeLike(params) | Rule::Generations { params, .. } => {
+                Some((params.birth.to_vec(), params.survival.to_vec()))
+            }
+            Rule::LargerThanLife(params) => {
+                let table = |ranges: &[(i32, i32)]| -> Vec<bool> {
+                    (0..=params.max_count())
+                        .map(|count| ranges.iter().any(|&(low, high)| count >= low && count <= high))
+                        .collect()
+                };
+                Some((table(&params.birth), table(&params.survival)))
+            }
+            _ => None,
+        }
+    }
+
+    // Updates one of the constants the shaders used to hard-code, by its GLSL name (b1, b2, s1,
+    // s2, R, T, m, s). For Larger than Life b1..s2 edit the first birth and survival range.
+    // The Lenia kernel radius is fixed; regenerate the kernel to change it.
+    pub fn set_param(&mut self, name: &str, value: f32) -> Result<(), String> {
+        let unknown = || format!("Unknown parameter {}", name);
+        match self {
+            Rule::LifeLike(_) | Rule::Generations { .. } => return Err(unknown()),
+            Rule::LargerThanLife(params) => {
+                let value = value as i32;
+                match name {
+                    "R" if (1..=MAX_RADIUS).contains(&value) => params.radius = value,
+                    "b1" => first_range(&mut params.birth, value).0 = value,
+                    "b2" => first_range(&mut params.birth, value).1 = value,
+                    "s1" => first_range(&mut params.survival, value).0 = value,
+                    "s2" => first_range(&mut params.survival, value).1 = value,
+                    "R" => return Err(format!("Invalid radius {}, expected 1 to {}", value, MAX_RADIUS)),
+                    _ => return Err(unknown()),
+                }
+            }
+            Rule::Primordia { params, .. } | Rule::PrimordiaTime { params, .. } => match name {
+                "R" if (1.0..=MAX_RADIUS as f32).contains(&value) => params.radius = value as i32,
+                "b1" => params.birth.0 = value,
+                "b2" => params.birth.1 = value,
+                "s1" => params.survival.0 = value,
+                "s2" => params.survival.1 = value,
+                "T" => match self {
+                    Rule::PrimordiaTime { time_step, .. } if value > 0.0 => *time_step = value,
+                    _ => return Err(format!("Invalid value {} for T", value)),
+                },
+                "R" => return Err(format!("Invalid radius {}, expected 1 to {}", value, MAX_RADIUS)),
+                _ => return Err(unknown()),
+            },
+            Rule::Lenia(params) => match name {
+                "T" if value > 0.0 => params.time_step = value,
+                "m" => params.mu = value,
+                "s" if value > 0.0 => params.sigma = value,
+                "T" | "s" => return Err(format!("Invalid value {} for {}", value, name)),
+                _ => return Err(unknown()),
+            },
+        }
+        Ok(())
+    }
+
+    // the states live in an 8-bit texture channel, so at most 256 of them
+    pub fn generations(params: LifeLikeParams, states: i32) -> Result<Self, String> {
+        if !(2..=256).contains(&states) {
+            return Err(format!("Generations rules need 2 to 256 states, got {}", states));
+        }
+        Ok(Rule::Generations { params, states })
+    }
+
+    pub fn larger_than_life() -> Self {
+        Rule::LargerThanLife(LtlParams::default())
+    }
+
+    // a cell is one of the levels 0 / states .. states / states, and those have to fit the
+    // 8-bit texture channel as well
+    pub fn primordia(params: PrimordiaParams, states: i32) -> Result<Self, String> {
+        if !(2..=255).contains(&states) {
+            return Err(format!("Primordia needs 2 to 255 states, got {}", states));
+        }
+        Ok(Rule::Primordia { params, states })
+    }
+
+    pub fn primordia_time() -> Self {
+        Rule::PrimordiaTime {
+            params: PrimordiaParams::default(),
+            time_step: 12.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lenia() -> Rule {
+        let kernel = Kernel::generate(5, &[1.0], KernelCore::Polynomial);
+        Rule::Lenia(LeniaParams::new(kernel, 10.0, 0.15, 0.015, GrowthFunction::Gaussian).unwrap())
+    }
+
+    fn primordia() -> Rule {
+        Rule::primordia(PrimordiaParams::default(), 12).unwrap()
+    }
+
+    #[test]
+    fn each_rule_takes_its_own_params() {
+        let accepted: [(Rule, &[&str]); 5] = [
+            (Rule::larger_than_life(), &["R", "b1", "b2", "s1", "s2"]),
+            (primordia(), &["R", "b1", "b2", "s1", "s2"]),
+            (Rule::primordia_time(), &["R", "b1", "b2", "s1", "s2", "T"]),
+            (lenia(), &["T", "m", "s"]),
+            (Rule::LifeLike(LifeLikeParams::default()), &[]),
+        ];
+        for (rule, names) in accepted {
+            for name in ["R", "b1", "b2", "s1", "s2", "T", "m", "s", "x"] {
+                let mut changed = rule.clone();
+                assert_eq!(changed.set_param(name, 3.0).is_ok(), names.contains(&name), "{} for {:?}", name, rule);
+            }
+        }
+        let mut generations = Rule::parse("B2/S/C3").unwrap();
+        assert!(generations.set_param("b1", 2.0).is_err());
+    }
+
+    #[test]
+    fn params_are_applied() {
+        let mut rule = Rule::larger_than_life();
+        rule.set_param("R", 7.0).unwrap();
+        rule.set_param("b1", 30.0).unwrap();
+        rule.set_param("s2", 60.0).unwrap();
+        let Rule::LargerThanLife(params) = &rule else { unreachable!() };
+        assert_eq!((params.radius, params.birth[0], params.survival[0]), (7, (30, 45), (34, 60)));
+
+        let mut rule = lenia();
+        rule.set_param("m", 0.2).unwrap();
+        rule.set_param("s", 0.03).unwrap();
+        rule.set_param("T", 5.0).unwrap();
+        let Rule::Lenia(params) = &rule else { unreachable!() };
+        assert_eq!((params.mu, params.sigma, params.time_step), (0.2, 0.03, 5.0));
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        for (mut rule, name, value) in [
+            (Rule::larger_than_life(), "R", 0.0),
+            (Rule::larger_than_life(), "R", 501.0),
+            (primordia(), "R", 0.5),
+            (primordia(), "R", 501.0),
+            (Rule::primordia_time(), "T", 0.0),
+            (Rule::primordia_time(), "T", -1.0),
+            (lenia(), "T", 0.0),
+            (lenia(), "s", 0.0),
+            (lenia(), "s", -0.01),
+        ] {
+            let before = rule.clone();
+            assert!(rule.set_param(name, value).is_err(), "{} = {} for {:?}", name, value, before);
+            assert_eq!(rule, before);
+        }
+    }
+}
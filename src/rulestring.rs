@@ -0,0 +1,271 @@
+// Parsing and formatting of rulestrings: B/S notation for Life-like and Generations rules
+// and the HROT / Golly notation for Larger than Life.
+
+use crate::rules::{LifeLikeParams, LtlParams, Neighbourhood, MAX_RADIUS};
+
+impl LifeLikeParams {
+    // "B36/S23", "b3/s23", "S23/B3" or the old "23/3" (survival first)
+    pub fn parse(rulestring: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid Life-like rule {}", rulestring);
+        let parts: Vec<&str> = rulestring.trim().split('/').collect();
+        if parts.len() != 2 {
+            return Err(invalid());
+        }
+
+        let mut birth = None;
+        let mut survival = None;
+        for (i, part) in parts.iter().enumerate() {
+            let (field, digits) = match part.chars().next() {
+                Some('B' | 'b') => (&mut birth, &part[1..]),
+                Some('S' | 's') => (&mut survival, &part[1..]),
+                // no letters: survival/birth
+                _ if i == 0 => (&mut survival, *part),
+                _ => (&mut birth, *part),
+            };
+            if field.is_some() {
+                return Err(invalid());
+            }
+            let mut counts = [false; 9];
+            for digit in digits.chars() {
+                match digit.to_digit(10) {
+                    Some(count) if count <= 8 => counts[count as usize] = true,
+                    _ => return Err(invalid()),
+                }
+            }
+            *field = Some(counts);
+        }
+
+        Ok(Self {
+            birth: birth.ok_or_else(invalid)?,
+            survival: survival.ok_or_else(invalid)?,
+        })
+    }
+
+    pub fn to_rulestring(&self) -> String {
+        let digits = |counts: &[bool; 9]| -> String {
+            (0..9).filter(|&i| counts[i]).map(|i| i.to_string()).collect()
+        };
+        format!("B{}/S{}", digits(&self.birth), digits(&self.survival))
+    }
+
+    // Generations rules, "B2/S/C3" or Golly's "/2/3" (survival/birth/states); returns the
+    // birth and survival counts with the number of states
+    pub fn parse_generations(rulestring: &str) -> Result<(Self, i32), String> {
+        let invalid = || format!("Invalid Generations rule {}", rulestring);
+        let parts: Vec<&str> = rulestring.trim().split('/').collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+
+        let states = parts
+            .iter()
+            .position(|part| part.starts_with(['C', 'c', 'G', 'g']))
+            .unwrap_or(2);
+        let counts: Vec<&str> = (0..3).filter(|&i| i != states).map(|i| parts[i]).collect();
+        let params = Self::parse(&counts.join("/")).map_err(|_| invalid())?;
+        let states = parts[states].trim_start_matches(['C', 'c', 'G', 'g']);
+        let states = states.parse().map_err(|_| invalid())?;
+        Ok((params, states))
+    }
+
+    pub fn to_generations_rulestring(&self, states: i32) -> String {
+        format!("{}/C{}", self.to_rulestring(), states)
+    }
+}
+
+impl LtlParams {
+    // HROT "R5,C0,S34-58,B34-45,NM" (centre cell not counted) or Golly
+    // "R5,C0,M1,S34..58,B34..45,NM", where M1 counts the centre cell. Ranges may be listed,
+    // as in "S2,4-6,B3", and the neighbourhood is NM (Moore), NN (von Neumann) or NC (circular).
+    pub fn parse(rulestring: &str) -> Result<Self, String> {
+        let invalid = |reason: &str| format!("Invalid Larger than Life rule {}: {}", rulestring, reason);
+        let number = |text: &str| text.trim().parse::<i32>().map_err(|_| invalid(text));
+        let range = |text: &str| -> Result<(i32, i32), String> {
+            match text.split_once("..").or_else(|| text.split_once('-')) {
+                Some((low, high)) => Ok((number(low)?, number(high)?)),
+                None => number(text).map(|count| (count, count)),
+            }
+        };
+
+        let mut params = Self {
+            radius: 1,
+            neighbourhood: Neighbourhood::Moore,
+            include_center: false,
+            birth: Vec::new(),
+            survival: Vec::new(),
+        };
+        let mut radius = None;
+        // the S or B field bare ranges are appended to
+        let mut current = None;
+        let (mut birth, mut survival) = (Vec::new(), Vec::new());
+
+        for token in rulestring.trim().split(',').map(str::trim) {
+            let mut chars = token.chars();
+            let field = chars.next().ok_or_else(|| invalid("empty field"))?;
+            let value = chars.as_str();
+            match field.to_ascii_uppercase() {
+                'R' => radius = Some(number(value)?),
+                'C' => match number(value)? {
+                    0..=2 => {}
+                    states => return Err(invalid(&format!("{} states are not supported", states))),
+                },
+                'M' => params.include_center = number(value)? == 1,
+                'S' | 'B' => {
+                    current = Some(field.to_ascii_uppercase());
+                    if !value.is_empty() {
+                        let list = if current == Some('S') { &mut survival } else { &mut birth };
+                        list.push(range(value)?);
+                    }
+                }
+                'N' => {
+                    params.neighbourhood = match value.to_ascii_uppercase().as_str() {
+                        "M" | "" => Neighbourhood::Moore,
+                        "N" => Neighbourhood::VonNeumann,
+                        "C" => Neighbourhood::Circular,
+                        other => return Err(invalid(&format!("unknown neighbourhood N{}", other))),
+                    };
+                    current = None;
+                }
+                '0'..='9' => match current {
+                    Some('S') => survival.push(range(token)?),
+                    Some(_) => birth.push(range(token)?),
+                    None => return Err(invalid(token)),
+                },
+                _ => return Err(invalid(token)),
+            }
+        }
+
+        params.radius = radius.ok_or_else(|| invalid("missing radius"))?;
+        if !(1..=MAX_RADIUS).contains(&params.radius) {
+            return Err(invalid(&format!("radius must be 1 to {}", MAX_RADIUS)));
+        }
+        params.birth = birth;
+        params.survival = survival;
+        let max = params.max_count();
+        for &(low, high) in params.birth.iter().chain(&params.survival) {
+            if low > high || low < 0 || high > max {
+                return Err(invalid(&format!("range {}-{} outside 0-{}", low, high, max)));
+            }
+        }
+        Ok(params)
+    }
+
+    pub fn to_rulestring(&self) -> String {
+        let ranges = |ranges: &[(i32, i32)]| -> String {
+            ranges
+                .iter()
+                .map(|&(low, high)| if low == high { low.to_string() } else { format!("{}..{}", low, high) })
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let neighbourhood = match self.neighbourhood {
+            Neighbourhood::Moore => "M",
+            Neighbourhood::VonNeumann => "N",
+            Neighbourhood::Circular => "C",
+        };
+        format!(
+            "R{},C0,M{},S{},B{},N{}",
+            self.radius,
+            self.include_center as i32,
+            ranges(&self.survival),
+            ranges(&self.birth),
+            neighbourhood,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(list: &[usize]) -> [bool; 9] {
+        let mut counts = [false; 9];
+        for &count in list {
+            counts[count] = true;
+        }
+        counts
+    }
+
+    #[test]
+    fn life_like_notations_agree() {
+        let highlife = LifeLikeParams {
+            birth: counts(&[3, 6]),
+            survival: counts(&[2, 3]),
+        };
+        assert_eq!(LifeLikeParams::parse("B36/S23"), Ok(highlife.clone()));
+        assert_eq!(LifeLikeParams::parse("b36/s23"), Ok(highlife.clone()));
+        assert_eq!(LifeLikeParams::parse("S23/B36"), Ok(highlife.clone()));
+        assert_eq!(LifeLikeParams::parse("23/36"), Ok(highlife));
+        let life = LifeLikeParams::parse("23/3").unwrap();
+        assert_eq!(LifeLikeParams::parse("S23/B3"), Ok(life.clone()));
+        assert_eq!(life.to_rulestring(), "B3/S23");
+    }
+
+    #[test]
+    fn invalid_life_like_rules_are_rejected() {
+        for rulestring in ["B9/S23", "B3", "B3/S23/S2", "B3/B2", "Bx/S23", ""] {
+            assert!(LifeLikeParams::parse(rulestring).is_err(), "{}", rulestring);
+        }
+    }
+
+    #[test]
+    fn generations_notations_agree() {
+        let (params, states) = LifeLikeParams::parse_generations("B2/S/C3").unwrap();
+        assert_eq!(params, LifeLikeParams::parse("B2/S").unwrap());
+        assert_eq!(states, 3);
+        assert_eq!(LifeLikeParams::parse_generations("/2/3"), Ok((params.clone(), 3)));
+        assert_eq!(params.to_generations_rulestring(states), "B2/S/C3");
+    }
+
+    #[test]
+    fn reads_hrot_rules() {
+        let params = LtlParams::parse("R5,C0,S34-58,B34-45,NM").unwrap();
+        assert_eq!(
+            params,
+            LtlParams {
+                radius: 5,
+                neighbourhood: Neighbourhood::Moore,
+                include_center: false,
+                birth: vec![(34, 45)],
+                survival: vec![(34, 58)],
+            }
+        );
+    }
+
+    #[test]
+    fn reads_golly_ranges_and_lists() {
+        assert_eq!(LtlParams::parse("R5,C0,M1,S34..58,B34..45,NM"), Ok(LtlParams::default()));
+        let params = LtlParams::parse("R2,C0,S2,4-6,B3..5,8,NN").unwrap();
+        assert_eq!(params.neighbourhood, Neighbourhood::VonNeumann);
+        assert_eq!(params.survival, vec![(2, 2), (4, 6)]);
+        assert_eq!(params.birth, vec![(3, 5), (8, 8)]);
+    }
+
+    #[test]
+    fn counts_outside_the_neighbourhood_are_rejected() {
+        // a radius 1 Moore neighbourhood holds 8 cells, 9 with the centre
+        assert!(LtlParams::parse("R1,C0,S2-8,B3,NM").is_ok());
+        assert!(LtlParams::parse("R1,C0,S2-9,B3,NM").is_err());
+        assert!(LtlParams::parse("R1,C0,M1,S2-9,B3,NM").is_ok());
+        assert!(LtlParams::parse("R1,C0,S5-2,B3,NM").is_err());
+        assert!(LtlParams::parse("R1,C0,S-1,B3,NM").is_err());
+        assert!(LtlParams::parse("R0,C0,S0,B1,NM").is_err());
+        assert!(LtlParams::parse("R500,C0,S0,B1,NM").is_ok());
+        assert!(LtlParams::parse("R5000,C0,S0,B1,NM").is_err());
+        assert!(LtlParams::parse("R5,C3,S34-58,B34-45,NM").is_err());
+        assert!(LtlParams::parse("C0,S2,B3,NM").is_err());
+    }
+
+    #[test]
+    fn rulestrings_round_trip() {
+        for rulestring in ["B3/S23", "B36/S23", "B/S012345678", "B2/S"] {
+            let params = LifeLikeParams::parse(rulestring).unwrap();
+            assert_eq!(params.to_rulestring(), rulestring);
+        }
+        for rulestring in ["R5,C0,M1,S34..58,B34..45,NM", "R2,C0,M0,S2,4..6,B3..5,8,NN", "R7,C0,M0,S,B10,NC"] {
+            let params = LtlParams::parse(rulestring).unwrap();
+            assert_eq!(params.to_rulestring(), rulestring);
+            assert_eq!(LtlParams::parse(&params.to_rulestring()), Ok(params));
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// The GLSL sources, embedded in the binary and put together here. A shader names the modules
+// it needs with `#include "growth/bell"` (a path under shaders/, without .glsl); each module is
+// included once, where it is first asked for. Constants that the rule decides, such as the
+// Lenia kernel radius, are passed as defines and go right after the #version line.
+
+use crate::rules::Rule;
+
+const MODULES: &[(&str, &str)] = &[
+    ("vertex", include_str!("../shaders/vertex.glsl")),
+    ("display", include_str!("../shaders/display.glsl")),
+    ("brush", include_str!("../shaders/brush.glsl")),
+    ("common/state", include_str!("../shaders/common/state.glsl")),
+    ("neighbourhood/average", include_str!("../shaders/neighbourhood/average.glsl")),
+    ("growth/bell", include_str!("../shaders/growth/bell.glsl")),
+    ("growth/polynomial", include_str!("../shaders/growth/polynomial.glsl")),
+    ("growth/step", include_str!("../shaders/growth/step.glsl")),
+    ("growth/primordia", include_str!("../shaders/growth/primordia.glsl")),
+    ("rules/gol", include_str!("../shaders/rules/gol.glsl")),
+    ("rules/generations", include_str!("../shaders/rules/generations.glsl")),
+    ("rules/ltl", include_str!("../shaders/rules/ltl.glsl")),
+    ("rules/primordia", include_str!("../shaders/rules/primordia.glsl")),
+    ("rules/primordia_time", include_str!("../shaders/rules/primordia_time.glsl")),
+    ("rules/lenia", include_str!("../shaders/rules/lenia.glsl")),
+];
+
+fn module(name: &str) -> Result<&'static str, String> {
+    MODULES
+        .iter()
+        .find(|(module, _)| *module == name)
+        .map(|(_, source)| *source)
+        .ok_or_else(|| format!("Unknown shader module {}", name))
+}
+
+// The complete source of the shader `name`, with its includes expanded and a #define for each
+// of `defines`
+pub fn assemble(name: &str, defines: &[(&str, String)]) -> Result<String, String> {
+    assemble_source(name, module(name)?, defines)
+}
+
+// Same as assemble for a source that isn't one of the modules, such as a shader written on
+// the page; `name` stands for it in errors
+pub fn assemble_source(name: &str, source: &str, defines: &[(&str, String)]) -> Result<String, String> {
+    let mut included = vec![name.to_string()];
+    let body = expand(name, source, &mut included)?;
+
+    let (version, body) = match body.strip_prefix("#version") {
+        Some(rest) => {
+            let end = rest.find('\n').map_or(rest.len(), |end| end + 1);
+            (format!("#version{}", &rest[..end]), &rest[end..])
+        }
+        None => (String::new(), body.as_str()),
+    };
+    let mut source = version;
+    for (define, value) in defines {
+        source.push_str(&format!("#define {} {}\n", define, value));
+    }
+    source.push_str(body);
+    Ok(source)
+}
+
+fn expand(name: &str, module_source: &str, included: &mut Vec<String>) -> Result<String, String> {
+    let mut source = String::new();
+    for (number, line) in module_source.lines().enumerate() {
+        match line.trim().strip_prefix("#include") {
+            Some(argument) => {
+                let include = argument
+                    .trim()
+                    .strip_prefix('"')
+                    .and_then(|argument| argument.strip_suffix('"'))
+                    .ok_or_else(|| format!("{}:{}: expected #include \"module\"", name, number + 1))?;
+                if !included.iter().any(|module| module == include) {
+                    included.push(include.to_string());
+                    source.push_str(&expand(include, module(include)?, included)?);
+                }
+            }
+            None => {
+                source.push_str(line);
+                source.push('\n');
+            }
+        }
+    }
+    Ok(source)
+}
+
+// the module of the fragment shader that steps the world under `rule`
+pub fn rule_module(rule: &Rule) -> &'static str {
+    match rule {
+        Rule::LifeLike(_) => "rules/gol",
+        Rule::Generations { .. } => "rules/generations",
+        Rule::LargerThanLife(_) => "rules/ltl",
+        Rule::Primordia { .. } => "rules/primordia",
+        Rule::PrimordiaTime { .. } => "rules/primordia_time",
+        Rule::Lenia(_) => "rules/lenia",
+    }
+}
+
+// the constants the shader of `rule` is built with
+pub fn rule_defines(rule: &Rule) -> Vec<(&'static str, String)> {
+    match rule {
+        Rule::Lenia(params) => vec![
+            ("R", params.kernel.radius.to_string()),
+            ("GROWTH", params.growth.index().to_string()),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+pub fn rule_shader(rule: &Rule) -> Result<String, String> {
+    assemble(rule_module(rule), &rule_defines(rule))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presets::Preset;
+
+    const STATE: &str = "uniform sampler2D u_current_state;";
+
+    #[test]
+    fn modules_are_included_once() {
+        let source = "#version 300 es\n#include \"common/state\"\n#include \"common/state\"\nvoid main() {}\n";
+        let assembled = assemble_source("page", source, &[]).unwrap();
+        assert_eq!(assembled.matches(STATE).count(), 1);
+        assert!(!assembled.contains("#include"));
+    }
+
+    #[test]
+    fn includes_are_nested() {
+        // neighbourhood/average includes common/state, which the shader asks for again
+        let source = "#version 300 es\n#include \"neighbourhood/average\"\n#include \"common/state\"\nvoid main() {}\n";
+        let assembled = assemble_source("page", source, &[]).unwrap();
+        assert_eq!(assembled.matches(STATE).count(), 1);
+        let state = assembled.find(STATE).unwrap();
+        let average = assembled.find("uniform int u_R;").unwrap();
+        assert!(state < average);
+    }
+
+    #[test]
+    fn bad_includes_are_errors() {
+        let unknown = assemble_source("page", "#include \"growth/none\"\n", &[]).unwrap_err();
+        assert_eq!(unknown, "Unknown shader module growth/none");
+        assert!(assemble("none", &[]).is_err());
+
+        let malformed = assemble_source("page", "#version 300 es\n#include common/state\n", &[]).unwrap_err();
+        assert_eq!(malformed, "page:2: expected #include \"module\"");
+        assert!(assemble_source("page", "#include \"common/state\n", &[]).is_err());
+    }
+
+    #[test]
+    fn defines_follow_the_version() {
+        let defines = [("R", String::from("13")), ("GROWTH", String::from("1"))];
+        let assembled = assemble_source("page", "#version 300 es\nvoid main() {}\n", &defines).unwrap();
+        assert_eq!(assembled, "#version 300 es\n#define R 13\n#define GROWTH 1\nvoid main() {}\n");
+
+        // without a #version line the defines come first
+        let assembled = assemble_source("page", "void main() {}\n", &defines).unwrap();
+        assert_eq!(assembled, "#define R 13\n#define GROWTH 1\nvoid main() {}\n");
+    }
+
+    #[test]
+    fn every_preset_has_a_shader() {
+        for preset in Preset::all() {
+            let source = rule_shader(&preset.rule).unwrap_or_else(|error| panic!("{}: {}", preset.id, error));
+            assert!(source.starts_with("#version 300 es\n"), "{}", preset.id);
+            assert!(!source.contains("#include"), "{}", preset.id);
+        }
+    }
+}
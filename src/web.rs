@@ -0,0 +1,1782 @@
+use crate::cpu::Grid;
+use crate::generators::{Generator, Seeding};
+use crate::gl_error::GlError;
+use crate::lenia::Animal;
+use crate::palette::{Palette, PALETTE_SIZE};
+use crate::patterns::{self, Orientation};
+use crate::presets::Preset;
+use crate::rules::{GrowthFunction, Kernel, KernelCore, LeniaParams, LifeLikeParams, Rule, MAX_RADIUS};
+use crate::rle::RlePattern;
+use crate::shaders;
+use js_sys::Math::random;
+use std::{cell::{Cell, RefCell}, rc::Rc};
+use wasm_bindgen::prelude::*;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer, WebGlFramebuffer, WebGlProgram, WebGlShader, WebGlTexture, WebGlVertexArrayObject, console, HtmlCanvasElement, MouseEvent, WheelEvent};
+use std::panic;
+
+
+thread_local! {
+    static SIMULATION: RefCell<Option<Rc<RefCell<Simulation>>>> = const { RefCell::new(None) };
+    // kept outside Simulation so that a reset does not resume a paused run or change the speed
+    static PAUSED: Cell<bool> = const { Cell::new(false) };
+    static GENERATIONS_PER_SECOND: Cell<f64> = const { Cell::new(60.0) };
+    static RESIZE_MODE: Cell<ResizeMode> = const { Cell::new(ResizeMode::Letterbox) };
+    // pattern a left click stamps instead of painting, as selected, and how it is turned
+    static STAMP: RefCell<Option<Vec<Vec<f64>>>> = const { RefCell::new(None) };
+    static STAMP_ORIENTATION: Cell<Orientation> = const { Cell::new(Orientation { quarter_turns: 0, mirrored: false }) };
+    // None starts every preset the way it defines
+    static GENERATOR: Cell<Option<Generator>> = const { Cell::new(None) };
+    static BRUSH: Cell<Brush> = const { Cell::new(Brush { radius: 2.0, shape: BrushShape::Circle, intensity: 1.0 }) };
+    // None draws every rule with its own colour map
+    static COLOUR_MAP: RefCell<Option<ColourMap>> = const { RefCell::new(None) };
+}
+
+// what GlErrors call a shader given to set_custom_shader
+const CUSTOM_SHADER: &str = "custom";
+
+// preset of the simulation created by start
+const DEFAULT_PRESET: &str = "gol";
+
+// fraction of live cells in the initial noise
+const DEFAULT_DENSITY: f64 = 0.5;
+
+// longest stretch between two frames that is turned into generations, in seconds. It bounds
+// the generations of a frame to the rate over 30, so that a rule slower than the rate runs
+// fewer generations per second instead of making every frame longer than the last.
+const MAX_FRAME_TIME: f64 = 1.0 / 30.0;
+
+// fastest rate set_generations_per_second accepts, the most the page offers
+const MAX_GENERATIONS_PER_SECOND: f64 = 1000.0;
+
+// what shaders/display.glsl draws: u_colour_map 0 looks the state up in the palette
+// texture, 1 shades live cells by their position
+#[derive(Debug, Clone, PartialEq)]
+enum ColourMap {
+    Palette(Palette),
+    Position,
+}
+
+impl ColourMap {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "position" => Some(ColourMap::Position),
+            name => Palette::from_name(name).map(ColourMap::Palette),
+        }
+    }
+
+    // the look each rule had when its shader did the colouring
+    fn for_rule(rule: &Rule) -> Self {
+        let palette = match rule {
+            Rule::LifeLike(_) | Rule::LargerThanLife(_) => return ColourMap::Position,
+            Rule::Generations { .. } => "fire",
+            Rule::Primordia { .. } | Rule::PrimordiaTime { .. } => "amber",
+            Rule::Lenia(_) => "gold",
+        };
+        Self::from_name(palette).unwrap()
+    }
+}
+
+// Brush for painting with the mouse: left drag paints, right drag erases. Binary rules set
+// the cells it touches; continuous ones gain or lose intensity times the brush weight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Brush {
+    // in cells
+    radius: f64,
+    shape: BrushShape,
+    intensity: f32,
+}
+
+// the discriminant is u_shape in shaders/brush.glsl
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BrushShape {
+    Circle = 0,
+    Square = 1,
+    Soft = 2,
+}
+
+// stamps shaders/brush.glsl takes per pass
+const MAX_BRUSH_STAMPS: usize = 16;
+
+// a brush movement between two points in texture coordinates; press and release are one-point
+// strokes
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BrushStroke {
+    from: (f64, f64),
+    to: (f64, f64),
+    erase: bool,
+}
+
+// what a mouse drag on the canvas does
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DragAction {
+    Pan,
+    Paint,
+    Erase,
+}
+
+// What a window resize does to the world
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ResizeMode {
+    // keep the world and scale it to fit the new canvas
+    Letterbox,
+    // keep the cell size on screen and grow or crop the world around its centre
+    Resize,
+}
+
+// View of the display pass. The centre is in texture coordinates (0..1, y up) and the world
+// wraps around, so any centre is valid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Camera {
+    zoom: f64,
+    center: (f64, f64),
+    // part of the world the canvas spans at zoom 1, kept up to date by present
+    extent: (f64, f64),
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            center: (0.5, 0.5),
+            extent: (1.0, 1.0),
+        }
+    }
+}
+
+impl Camera {
+    const MIN_ZOOM: f64 = 0.25;
+    const MAX_ZOOM: f64 = 64.0;
+
+    // zooms by factor keeping the world point under (x, y) in place; x and y are fractions of
+    // the canvas, y up
+    fn zoom_at(&mut self, factor: f64, x: f64, y: f64) {
+        let zoom = (self.zoom * factor).clamp(Self::MIN_ZOOM, Self::MAX_ZOOM);
+        let offset = |screen: f64, extent: f64| (screen - 0.5) * extent * (1.0 / self.zoom - 1.0 / zoom);
+        self.center = (self.center.0 + offset(x, self.extent.0), self.center.1 + offset(y, self.extent.1));
+        self.zoom = zoom;
+    }
+
+    // point of the world, in texture coordinates, under (x, y) in fractions of the canvas; None
+    // in the letterbox
+    fn world_position(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let offset = ((x - 0.5) * self.extent.0 / self.zoom, (y - 0.5) * self.extent.1 / self.zoom);
+        if offset.0.abs() > 0.5 || offset.1.abs() > 0.5 {
+            return None;
+        }
+        Some(((self.center.0 + offset.0).rem_euclid(1.0), (self.center.1 + offset.1).rem_euclid(1.0)))
+    }
+
+    // moves the world by (dx, dy), in fractions of the canvas, y up
+    fn pan(&mut self, dx: f64, dy: f64) {
+        self.center = (
+            (self.center.0 - dx * self.extent.0 / self.zoom).rem_euclid(1.0),
+            (self.center.1 - dy * self.extent.1 / self.zoom).rem_euclid(1.0),
+        );
+    }
+}
+
+// Part of the world the canvas spans at zoom 1, per axis. The world is scaled to fit, so one
+// axis is 1 and the other at least 1, the rest being letterbox.
+fn view_extent(canvas: &HtmlCanvasElement, width: i32, height: i32) -> (f64, f64) {
+    let (canvas_width, canvas_height) = (canvas.width() as f64, canvas.height() as f64);
+    let fit = (canvas_width / width as f64).min(canvas_height / height as f64);
+    (canvas_width / (fit * width as f64), canvas_height / (fit * height as f64))
+}
+
+// Storage of the state textures. Float textures keep the small 1/T updates of Lenia that 8-bit
+// channels round away, but rendering to them needs EXT_color_buffer_float.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StatePrecision {
+    Float32,
+    Byte,
+}
+
+impl StatePrecision {
+    fn detect(context: &WebGl2RenderingContext) -> Self {
+        match context.get_extension("EXT_color_buffer_float") {
+            Ok(Some(_)) => StatePrecision::Float32,
+            _ => {
+                let error = GlError::ExtensionMissing {
+                    name: "EXT_color_buffer_float".to_string(),
+                };
+                console::warn_2(&error.into(), &"falling back to 8-bit state".into());
+                StatePrecision::Byte
+            }
+        }
+    }
+
+    fn internal_format(self) -> i32 {
+        match self {
+            StatePrecision::Float32 => WebGl2RenderingContext::RGBA32F as i32,
+            StatePrecision::Byte => WebGl2RenderingContext::RGBA8 as i32,
+        }
+    }
+
+    fn data_type(self) -> u32 {
+        match self {
+            StatePrecision::Float32 => WebGl2RenderingContext::FLOAT,
+            StatePrecision::Byte => WebGl2RenderingContext::UNSIGNED_BYTE,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            StatePrecision::Float32 => "float32",
+            StatePrecision::Byte => "uint8",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Simulation {
+    context: WebGl2RenderingContext,
+    vertex_shader: WebGlShader,
+    vertex_buffer: WebGlBuffer,
+    vertex_array: WebGlVertexArrayObject,
+    program: WebGlProgram,
+    // assembled source of the program's fragment shader, which set_rule replaces when the
+    // rule needs another one
+    fragment_source: String,
+    display_program: WebGlProgram,
+    palette_texture: WebGlTexture,
+    // the palette last uploaded to palette_texture
+    palette: Option<Palette>,
+    canvas: HtmlCanvasElement,
+    current_framebuffer: Rc<RefCell<WebGlFramebuffer>>,
+    next_framebuffer: Rc<RefCell<WebGlFramebuffer>>,
+    current_texture: Rc<RefCell<WebGlTexture>>,
+    next_texture: Rc<RefCell<WebGlTexture>>,
+    mouse_position: Rc<RefCell<(f64, f64)>>,
+    camera: Rc<RefCell<Camera>>,
+    brush_program: WebGlProgram,
+    // filled by the mouse listeners, applied at the next animation frame
+    brush_strokes: Rc<RefCell<Vec<BrushStroke>>>,
+    // clicks with a stamp selected, in texture coordinates
+    pending_stamps: Rc<RefCell<Vec<(f64, f64)>>>,
+    // world size in cells, independent of the canvas
+    width: i32,
+    height: i32,
+    states: i32,
+    // of the initial world, so that the run can be replayed
+    seeding: Seeding,
+    precision: StatePrecision,
+    rule_texture: WebGlTexture,
+    rule: Rule,
+    // animation frame timestamp in milliseconds, and generations owed but not yet run
+    last_frame_time: Option<f64>,
+    pending_generations: f64,
+}
+
+impl Simulation {
+    fn new(canvas: HtmlCanvasElement, width: i32, height: i32, states: i32, seeding: Seeding, rule: Rule) -> Result<Self, JsValue> {
+        let context = canvas
+            .get_context("webgl2")?
+            .unwrap()
+            .dyn_into::<WebGl2RenderingContext>()?;
+
+        let max_size = context
+            .get_parameter(WebGl2RenderingContext::MAX_TEXTURE_SIZE)?
+            .as_f64()
+            .unwrap_or(2048.0) as i32;
+        if width < 1 || height < 1 || width > max_size || height > max_size {
+            return Err(format!("Invalid world size {}x{}, the limit is {}x{}", width, height, max_size, max_size).into());
+        }
+
+        let vert_shader = compile_shader(
+            &context,
+            WebGl2RenderingContext::VERTEX_SHADER,
+            "vertex",
+            &shaders::assemble("vertex", &[])?,
+        )?;
+
+        let fragment_source = shaders::rule_shader(&rule)?;
+        let program = link_rule_program(&context, &vert_shader, shaders::rule_module(&rule), &fragment_source)?;
+
+        let display_shader = compile_shader(
+            &context,
+            WebGl2RenderingContext::FRAGMENT_SHADER,
+            "display",
+            &shaders::assemble("display", &[])?,
+        )?;
+        let display_program = link_program(&context, "display", &vert_shader, &display_shader);
+        context.delete_shader(Some(&display_shader));
+        let display_program = display_program?;
+
+        let brush_shader = compile_shader(
+            &context,
+            WebGl2RenderingContext::FRAGMENT_SHADER,
+            "brush",
+            &shaders::assemble("brush", &[])?,
+        )?;
+        let brush_program = link_program(&context, "brush", &vert_shader, &brush_shader);
+        context.delete_shader(Some(&brush_shader));
+        let brush_program = brush_program?;
+
+        context.use_program(Some(&program));
+
+        let vertices: [f32; 8] = [-1.0, -1.0, 1.0, -1.0, -1.0, 1.0, 1.0, 1.0];
+        let (vertex_buffer, vertex_array) = setup_vertex_buffer(&context, &program, &vertices)?;
+
+        let precision = StatePrecision::detect(&context);
+        let (framebuffer1, texture1) = create_framebuffer(&context, width, height, precision)?;
+        let (framebuffer2, texture2) = create_framebuffer(&context, width, height, precision)?;
+
+        let grid = seeding.grid(width as usize, height as usize, !rule.is_binary());
+        upload_state(&context, &texture1, &grid, precision)?;
+
+        let rule_texture = context.create_texture().ok_or("Failed to create texture")?;
+        let palette_texture = context.create_texture().ok_or("Failed to create texture")?;
+
+        let mut simulation = Self {
+            context,
+            vertex_shader: vert_shader,
+            vertex_buffer,
+            vertex_array,
+            program,
+            fragment_source,
+            display_program,
+            palette_texture,
+            palette: None,
+            canvas,
+            current_framebuffer: Rc::new(RefCell::new(framebuffer1)),
+            next_framebuffer: Rc::new(RefCell::new(framebuffer2)),
+            current_texture: Rc::new(RefCell::new(texture1)),
+            next_texture: Rc::new(RefCell::new(texture2)),
+            mouse_position: Rc::new(RefCell::new((0.0, 0.0))),
+            camera: Rc::new(RefCell::new(Camera::default())),
+            brush_program,
+            brush_strokes: Rc::new(RefCell::new(Vec::new())),
+            pending_stamps: Rc::new(RefCell::new(Vec::new())),
+            width,
+            height,
+            states,
+            seeding,
+            precision,
+            rule_texture,
+            rule: Rule::LifeLike(LifeLikeParams::default()),
+            last_frame_time: None,
+            pending_generations: 0.0,
+        };
+        simulation.set_rule(rule)?;
+        Ok(simulation)
+    }
+
+    // frees the GL objects of a simulation that a reset replaces, since they share the context
+    // with the new one
+    fn destroy(&self) {
+        let context = &self.context;
+        for program in [&self.program, &self.display_program, &self.brush_program] {
+            context.delete_program(Some(program));
+        }
+        context.delete_shader(Some(&self.vertex_shader));
+        context.delete_vertex_array(Some(&self.vertex_array));
+        context.delete_buffer(Some(&self.vertex_buffer));
+        context.delete_framebuffer(Some(&self.current_framebuffer.borrow()));
+        context.delete_framebuffer(Some(&self.next_framebuffer.borrow()));
+        context.delete_texture(Some(&self.current_texture.borrow()));
+        context.delete_texture(Some(&self.next_texture.borrow()));
+        context.delete_texture(Some(&self.rule_texture));
+        context.delete_texture(Some(&self.palette_texture));
+    }
+
+    // The rule parameters are sent as uniforms every frame. Lookup data, the Lenia kernel or the
+    // birth/survival table of the binary rules, goes into rule_texture here. A rule of another
+    // kind, or a Lenia kernel of another radius, gets its own shader, compiled here.
+    fn set_rule(&mut self, rule: Rule) -> Result<(), JsValue> {
+        // the tables first, since a neighbourhood too large for them leaves the rule unchanged
+        if let Rule::Lenia(params) = &rule {
+            upload_kernel(&self.context, &self.rule_texture, &params.kernel)?;
+        }
+        if let Some((birth, survival)) = rule.transition_table() {
+            upload_transition_table(&self.context, &self.rule_texture, &birth, &survival)?;
+        }
+        self.set_fragment_source(shaders::rule_module(&rule), shaders::rule_shader(&rule)?)?;
+        // Generations and Primordia rules bring their own state count for u_states
+        if let Rule::Generations { states, .. } | Rule::Primordia { states, .. } = &rule {
+            self.states = *states;
+        }
+        self.rule = rule;
+        Ok(())
+    }
+
+    // steps the world with `fragment_source`, the assembled fragment shader `name`, from the
+    // next generation on; the running program stays if it fails to build
+    fn set_fragment_source(&mut self, name: &str, fragment_source: String) -> Result<(), GlError> {
+        if fragment_source != self.fragment_source {
+            let program = link_rule_program(&self.context, &self.vertex_shader, name, &fragment_source)?;
+            let old_program = std::mem::replace(&mut self.program, program);
+            self.context.delete_program(Some(&old_program));
+            self.fragment_source = fragment_source;
+        }
+        Ok(())
+    }
+
+    fn set_rule_uniforms(&self) {
+        let uniform = |name: &str| self.context.get_uniform_location(&self.program, name);
+        let set_int = |name: &str, value: i32| self.context.uniform1i(uniform(name).as_ref(), value);
+        let set_float = |name: &str, value: f32| self.context.uniform1f(uniform(name).as_ref(), value);
+        match &self.rule {
+            Rule::LifeLike(_) | Rule::Generations { .. } | Rule::LargerThanLife(_) => {
+                self.context.active_texture(WebGl2RenderingContext::TEXTURE1);
+                self.context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&self.rule_texture));
+                set_int("u_rule", 1);
+                if let Rule::LargerThanLife(params) = &self.rule {
+                    set_int("u_R", params.radius);
+                    set_int("u_neighbourhood", params.neighbourhood as i32);
+                    set_int("u_include_center", params.include_center as i32);
+                }
+                self.context.active_texture(WebGl2RenderingContext::TEXTURE0);
+            }
+            Rule::Primordia { params, .. } | Rule::PrimordiaTime { params, .. } => {
+                set_int("u_R", params.radius);
+                set_float("u_b1", params.birth.0);
+                set_float("u_b2", params.birth.1);
+                set_float("u_s1", params.survival.0);
+                set_float("u_s2", params.survival.1);
+                if let Rule::PrimordiaTime { time_step, .. } = &self.rule {
+                    set_float("u_T", *time_step);
+                }
+            }
+            Rule::Lenia(params) => {
+                self.context.active_texture(WebGl2RenderingContext::TEXTURE1);
+                self.context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&self.rule_texture));
+                set_int("u_kernel", 1);
+                set_float("u_T", params.time_step);
+                set_float("u_m", params.mu);
+                set_float("u_s", params.sigma);
+                self.context.active_texture(WebGl2RenderingContext::TEXTURE0);
+            }
+        }
+    }
+
+    // stamps the selected pattern at the clicks queued by the mouse listeners
+    fn apply_stamps(&self) -> Result<(), JsValue> {
+        let points = std::mem::take(&mut *self.pending_stamps.borrow_mut());
+        if points.is_empty() {
+            return Ok(());
+        }
+        let Some(pattern) = STAMP.with(|stamp| stamp.borrow().clone()) else {
+            return Ok(());
+        };
+        let pattern = patterns::oriented(&pattern, STAMP_ORIENTATION.with(Cell::get));
+        // only the cells under the stamp are written, the rest of the world stays on the GPU
+        let texture = self.current_texture.borrow();
+        for (x, y) in points {
+            // texture coordinates are y up, stamp counts rows from the top
+            let x = (x * self.width as f64).floor() as i32;
+            let y = ((1.0 - y) * self.height as f64).floor() as i32;
+            for (left, bottom, piece) in patterns::stamp_pieces(self.width as usize, self.height as usize, &pattern, x, y) {
+                upload_state_region(&self.context, &texture, left as i32, bottom as i32, &piece, self.precision)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Applies the strokes queued by the mouse listeners. Strokes become stamps half a radius
+    // apart, drawn MAX_BRUSH_STAMPS at a time into the next framebuffer like a generation.
+    fn apply_brush_strokes(&self) {
+        let strokes = std::mem::take(&mut *self.brush_strokes.borrow_mut());
+        if strokes.is_empty() {
+            return;
+        }
+        let brush = BRUSH.with(Cell::get);
+        let (width, height) = (self.width as f64, self.height as f64);
+
+        let mut stamps = Vec::new();
+        for stroke in strokes {
+            let from = (stroke.from.0 * width, stroke.from.1 * height);
+            // shortest way around the torus
+            let delta = (
+                ((stroke.to.0 - stroke.from.0 + 0.5).rem_euclid(1.0) - 0.5) * width,
+                ((stroke.to.1 - stroke.from.1 + 0.5).rem_euclid(1.0) - 0.5) * height,
+            );
+            let steps = ((delta.0.hypot(delta.1) / (brush.radius / 2.0).max(0.5)).ceil() as usize).max(1);
+            let first = if stroke.from == stroke.to { steps } else { 1 };
+            for step in first..=steps {
+                let t = step as f64 / steps as f64;
+                let x = (from.0 + delta.0 * t).rem_euclid(width);
+                let y = (from.1 + delta.1 * t).rem_euclid(height);
+                stamps.extend_from_slice(&[x as f32, y as f32, stroke.erase as i32 as f32]);
+            }
+        }
+
+        let uniform = |name: &str| self.context.get_uniform_location(&self.brush_program, name);
+        let binary = self.rule.is_binary();
+        for batch in stamps.chunks(MAX_BRUSH_STAMPS * 3) {
+            self.context.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, Some(&self.next_framebuffer.borrow()));
+            self.context.viewport(0, 0, self.width, self.height);
+            self.context.use_program(Some(&self.brush_program));
+            self.context.active_texture(WebGl2RenderingContext::TEXTURE0);
+            self.context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&self.current_texture.borrow()));
+            self.context.uniform1i(uniform("u_current_state").as_ref(), 0);
+            self.context.uniform2f(uniform("u_resolution").as_ref(), self.width as f32, self.height as f32);
+            self.context.uniform1f(uniform("u_radius").as_ref(), brush.radius as f32);
+            self.context.uniform1i(uniform("u_shape").as_ref(), brush.shape as i32);
+            self.context.uniform1f(uniform("u_intensity").as_ref(), brush.intensity);
+            self.context.uniform1i(uniform("u_binary").as_ref(), binary as i32);
+            self.context.uniform3fv_with_f32_array(uniform("u_stamps").as_ref(), batch);
+            self.context.uniform1i(uniform("u_stamp_count").as_ref(), (batch.len() / 3) as i32);
+            self.context.draw_arrays(WebGl2RenderingContext::TRIANGLE_STRIP, 0, 4);
+
+            std::mem::swap(&mut *self.current_framebuffer.borrow_mut(), &mut *self.next_framebuffer.borrow_mut());
+            std::mem::swap(&mut *self.current_texture.borrow_mut(), &mut *self.next_texture.borrow_mut());
+        }
+    }
+
+    // Replaces the state textures with width x height ones holding the current cells,
+    // centred and cropped or padded with dead cells
+    fn resize_world(&mut self, width: i32, height: i32) -> Result<(), JsValue> {
+        if (width, height) == (self.width, self.height) {
+            return Ok(());
+        }
+        let max_size = self
+            .context
+            .get_parameter(WebGl2RenderingContext::MAX_TEXTURE_SIZE)?
+            .as_f64()
+            .unwrap_or(2048.0) as i32;
+        if width < 1 || height < 1 || width > max_size || height > max_size {
+            return Err(format!("Invalid world size {}x{}, the limit is {}x{}", width, height, max_size, max_size).into());
+        }
+
+        let grid = self.read_grid()?.resized(width as usize, height as usize);
+        let (framebuffer1, texture1) = create_framebuffer(&self.context, width, height, self.precision)?;
+        let (framebuffer2, texture2) = create_framebuffer(&self.context, width, height, self.precision)?;
+        upload_state(&self.context, &texture1, &grid, self.precision)?;
+
+        let old_framebuffers = [
+            std::mem::replace(&mut *self.current_framebuffer.borrow_mut(), framebuffer1),
+            std::mem::replace(&mut *self.next_framebuffer.borrow_mut(), framebuffer2),
+        ];
+        let old_textures = [
+            std::mem::replace(&mut *self.current_texture.borrow_mut(), texture1),
+            std::mem::replace(&mut *self.next_texture.borrow_mut(), texture2),
+        ];
+        for framebuffer in &old_framebuffers {
+            self.context.delete_framebuffer(Some(framebuffer));
+        }
+        for texture in &old_textures {
+            self.context.delete_texture(Some(texture));
+        }
+
+        self.width = width;
+        self.height = height;
+        Ok(())
+    }
+
+    // follows the canvas to a new size according to RESIZE_MODE
+    fn resize_canvas(&mut self, canvas_width: u32, canvas_height: u32) -> Result<(), JsValue> {
+        let (old_width, old_height) = (self.canvas.width() as f64, self.canvas.height() as f64);
+        self.canvas.set_width(canvas_width);
+        self.canvas.set_height(canvas_height);
+        match RESIZE_MODE.with(Cell::get) {
+            // the display pass scales the world to whatever the canvas is
+            ResizeMode::Letterbox => Ok(()),
+            ResizeMode::Resize => {
+                let cell_size = (old_width / self.width as f64).min(old_height / self.height as f64);
+                let width = (canvas_width as f64 / cell_size).round().max(1.0) as i32;
+                let height = (canvas_height as f64 / cell_size).round().max(1.0) as i32;
+                self.resize_world(width, height)
+            }
+        }
+    }
+
+    fn load_grid(&self, grid: &Grid) -> Result<(), JsValue> {
+        upload_state(&self.context, &self.current_texture.borrow(), grid, self.precision)
+    }
+
+    // reads the current state texture back through its framebuffer
+    fn read_grid(&self) -> Result<Grid, JsValue> {
+        let (width, height) = (self.width, self.height);
+        let length = (width * height * 4) as usize;
+        self.context.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, Some(&self.current_framebuffer.borrow()));
+        let grid = match self.precision {
+            StatePrecision::Float32 => {
+                let pixels = js_sys::Float32Array::new_with_length(length as u32);
+                self.context.read_pixels_with_opt_array_buffer_view(
+                    0,
+                    0,
+                    width,
+                    height,
+                    WebGl2RenderingContext::RGBA,
+                    WebGl2RenderingContext::FLOAT,
+                    Some(&pixels),
+                )?;
+                Grid::from_rgba32f(width as usize, height as usize, &pixels.to_vec())
+            }
+            StatePrecision::Byte => {
+                let mut pixels = vec![0u8; length];
+                self.context.read_pixels_with_opt_u8_array(
+                    0,
+                    0,
+                    width,
+                    height,
+                    WebGl2RenderingContext::RGBA,
+                    WebGl2RenderingContext::UNSIGNED_BYTE,
+                    Some(&mut pixels),
+                )?;
+                Grid::from_rgba8(width as usize, height as usize, &pixels)
+            }
+        };
+        self.context.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+        Ok(grid?)
+    }
+
+    // called once per animation frame: runs as many generations as the time since the last
+    // frame is worth at generations_per_second, then presents the result once
+    fn frame(&mut self, time: f64, generations_per_second: f64) {
+        let elapsed = match self.last_frame_time {
+            // a long stall, e.g. a slow kernel or a background tab, skips generations
+            // instead of trying to catch up
+            Some(last) => ((time - last) / 1000.0).min(MAX_FRAME_TIME),
+            None => 0.0,
+        };
+        self.last_frame_time = Some(time);
+        self.pending_generations += elapsed * generations_per_second;
+        let generations = self.pending_generations.floor();
+        for _ in 0..generations as u32 {
+            self.step_generation();
+        }
+        // only the fraction of a generation carries over to the next frame
+        self.pending_generations -= generations;
+        self.present();
+    }
+
+    // forgets the time of the last frame, so a pause does not count as elapsed time
+    fn stop_clock(&mut self) {
+        self.last_frame_time = None;
+        self.pending_generations = 0.0;
+    }
+
+    fn set_uniforms(&self) {
+        self.context.use_program(Some(&self.program));
+
+        self.context.active_texture(WebGl2RenderingContext::TEXTURE0);
+        self.context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&self.current_texture.borrow()));
+
+        let u_current_state_location = self.context.get_uniform_location(&self.program, "u_current_state");
+        self.context.uniform1i(u_current_state_location.as_ref(), 0);
+
+        let resolution_location = self.context.get_uniform_location(&self.program, "u_resolution");
+        self.context.uniform2f(resolution_location.as_ref(), self.width as f32, self.height as f32);
+
+        let u_states_location = self.context.get_uniform_location(&self.program, "u_states");
+        self.context.uniform1f(u_states_location.as_ref(), self.states as f32);
+
+        self.set_rule_uniforms();
+    }
+
+    // Calculate the next state into the other framebuffer and make it the current one
+    fn step_generation(&self) {
+        self.context.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, Some(&self.next_framebuffer.borrow()));
+        self.context.viewport(0, 0, self.width, self.height);
+        self.set_uniforms();
+        self.context.draw_arrays(WebGl2RenderingContext::TRIANGLE_STRIP, 0, 4);
+
+        // Swap framebuffers and textures
+        std::mem::swap(&mut *self.current_framebuffer.borrow_mut(), &mut *self.next_framebuffer.borrow_mut());
+        std::mem::swap(&mut *self.current_texture.borrow_mut(), &mut *self.next_texture.borrow_mut());
+    }
+
+    // Render the current state to the canvas through the display program
+    fn present(&mut self) {
+        let uniform = |name: &str| self.context.get_uniform_location(&self.display_program, name);
+        self.context.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+        self.context.viewport(0, 0, self.canvas.width() as i32, self.canvas.height() as i32);
+        self.context.use_program(Some(&self.display_program));
+
+        self.context.active_texture(WebGl2RenderingContext::TEXTURE0);
+        self.context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&self.current_texture.borrow()));
+        self.context.uniform1i(uniform("u_state").as_ref(), 0);
+        self.context.uniform2f(uniform("u_resolution").as_ref(), self.canvas.width() as f32, self.canvas.height() as f32);
+        self.context.uniform2f(uniform("u_world").as_ref(), self.width as f32, self.height as f32);
+        let colour_map = COLOUR_MAP.with(|colour_map| colour_map.borrow().clone());
+        match colour_map.unwrap_or_else(|| ColourMap::for_rule(&self.rule)) {
+            ColourMap::Palette(palette) => {
+                if self.palette.as_ref() != Some(&palette) {
+                    if let Err(error) = upload_palette(&self.context, &self.palette_texture, &palette) {
+                        console::error_1(&error);
+                    }
+                    self.palette = Some(palette);
+                }
+                self.context.active_texture(WebGl2RenderingContext::TEXTURE1);
+                self.context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&self.palette_texture));
+                self.context.uniform1i(uniform("u_palette").as_ref(), 1);
+                self.context.active_texture(WebGl2RenderingContext::TEXTURE0);
+                self.context.uniform1i(uniform("u_colour_map").as_ref(), 0);
+            }
+            ColourMap::Position => self.context.uniform1i(uniform("u_colour_map").as_ref(), 1),
+        }
+
+        self.camera.borrow_mut().extent = view_extent(&self.canvas, self.width, self.height);
+        let camera = *self.camera.borrow();
+        self.context.uniform1f(uniform("u_zoom").as_ref(), camera.zoom as f32);
+        self.context.uniform2f(uniform("u_center").as_ref(), camera.center.0 as f32, camera.center.1 as f32);
+
+        let (mouse_x, mouse_y) = *self.mouse_position.borrow();
+        self.context.uniform2f(uniform("u_mouse").as_ref(), mouse_x as f32, (self.canvas.height() as f64 - mouse_y) as f32);
+
+        self.context.draw_arrays(WebGl2RenderingContext::TRIANGLE_STRIP, 0, 4);
+
+        if let Err(error) = check_gl_error(&self.context, "After render loop") {
+            console::error_1(&error.into());
+        }
+    }
+}
+
+#[wasm_bindgen(start)]
+fn start() -> Result<(), JsValue> {
+    panic::set_hook(Box::new(console_error_panic_hook::hook));
+    console::log_1(&"Starting WebAssembly...".into());
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    let canvas = document.get_element_by_id("canvas").unwrap();
+    let canvas: HtmlCanvasElement = canvas.dyn_into::<web_sys::HtmlCanvasElement>()?;
+
+    // set the canvas size to the window size
+    canvas.set_width(document.body().unwrap().client_width() as u32);
+    canvas.set_height(document.body().unwrap().client_height() as u32);
+
+    let preset = Preset::find(DEFAULT_PRESET).unwrap();
+    let simulation = Simulation::new(
+        canvas,
+        preset.world.0 as i32,
+        preset.world.1 as i32,
+        0,
+        Seeding {
+            generator: preset.start,
+            seed: random_seed(),
+            density: DEFAULT_DENSITY,
+        },
+        preset.rule,
+    )?;
+    setup_canvas_listeners(&simulation.canvas)?;
+    let simulation = Rc::new(RefCell::new(simulation));
+    SIMULATION.with(|sim| {
+        *sim.borrow_mut() = Some(simulation.clone());
+    });
+
+    let f = Rc::new(RefCell::new(None));
+    let g = f.clone();
+
+    *g.borrow_mut() = Some(Closure::wrap(Box::new(move |time: f64| {
+        SIMULATION.with(|sim| {
+            if let Some(simulation) = sim.borrow().as_ref() {
+                let mut simulation = simulation.borrow_mut();
+                simulation.apply_brush_strokes();
+                if let Err(error) = simulation.apply_stamps() {
+                    console::error_1(&error);
+                }
+                if PAUSED.with(Cell::get) {
+                    simulation.stop_clock();
+                    simulation.present();
+                } else {
+                    simulation.frame(time, GENERATIONS_PER_SECOND.with(Cell::get));
+                }
+            }
+        });
+        request_animation_frame(f.borrow().as_ref().unwrap());
+    }) as Box<dyn FnMut(f64)>));
+    
+    request_animation_frame(g.borrow().as_ref().unwrap());
+
+    let resize = Closure::wrap(Box::new(move || {
+        let body = web_sys::window().unwrap().document().unwrap().body().unwrap();
+        let result = with_simulation(|simulation| {
+            simulation.resize_canvas(body.client_width() as u32, body.client_height() as u32)
+        });
+        if let Err(error) = result {
+            console::error_1(&error);
+        }
+    }) as Box<dyn FnMut()>);
+    web_sys::window()
+        .unwrap()
+        .add_event_listener_with_callback("resize", resize.as_ref().unchecked_ref())?;
+    resize.forget();
+
+    console::log_1(&"WebAssembly started successfully.".into());
+
+    Ok(())
+}
+
+// runs `f` on the current simulation, for the canvas listeners; they outlive every reset
+fn with_current_simulation(f: impl FnOnce(&Simulation)) {
+    SIMULATION.with(|simulation| {
+        if let Some(simulation) = simulation.borrow().as_ref() {
+            f(&simulation.borrow());
+        }
+    });
+}
+
+// Wheel zooms around the cursor. Dragging with the left button paints, with the right one
+// erases and with the middle one, or the left one and shift, pans. While a stamp is
+// selected, a left click stamps it instead of painting. Registered once by start, the
+// listeners act on whichever simulation is current.
+fn setup_canvas_listeners(canvas: &HtmlCanvasElement) -> Result<(), JsValue> {
+    let drag = Rc::new(Cell::new(None));
+
+    let target = canvas.clone();
+    let wheel = Closure::wrap(Box::new(move |event: WheelEvent| {
+        event.prevent_default();
+        let (x, y) = canvas_position(&target, &event);
+        with_current_simulation(|simulation| {
+            simulation.camera.borrow_mut().zoom_at((-event.delta_y() * 0.002).exp(), x, y)
+        });
+    }) as Box<dyn FnMut(_)>);
+    canvas.add_event_listener_with_callback("wheel", wheel.as_ref().unchecked_ref())?;
+    wheel.forget();
+
+    let current_drag = drag.clone();
+    let target = canvas.clone();
+    let mousedown = Closure::wrap(Box::new(move |event: MouseEvent| {
+        let action = match event.button() {
+            0 if event.shift_key() => DragAction::Pan,
+            0 if STAMP.with(|stamp| stamp.borrow().is_some()) => {
+                let (x, y) = canvas_position(&target, &event);
+                with_current_simulation(|simulation| {
+                    if let Some(point) = simulation.camera.borrow().world_position(x, y) {
+                        simulation.pending_stamps.borrow_mut().push(point);
+                    }
+                });
+                return;
+            }
+            0 => DragAction::Paint,
+            1 => DragAction::Pan,
+            2 => DragAction::Erase,
+            _ => return,
+        };
+        event.prevent_default();
+        let (x, y) = canvas_position(&target, &event);
+        if action != DragAction::Pan {
+            with_current_simulation(|simulation| {
+                if let Some(point) = simulation.camera.borrow().world_position(x, y) {
+                    simulation.brush_strokes.borrow_mut().push(BrushStroke {
+                        from: point,
+                        to: point,
+                        erase: action == DragAction::Erase,
+                    });
+                }
+            });
+        }
+        current_drag.set(Some((action, (x, y))));
+    }) as Box<dyn FnMut(_)>);
+    canvas.add_event_listener_with_callback("mousedown", mousedown.as_ref().unchecked_ref())?;
+    mousedown.forget();
+
+    // keeps the cursor position for the display shader, and drags
+    let current_drag = drag.clone();
+    let target = canvas.clone();
+    let mousemove = Closure::wrap(Box::new(move |event: MouseEvent| {
+        let rect = target.get_bounding_client_rect();
+        let cursor = (event.client_x() as f64 - rect.left(), event.client_y() as f64 - rect.top());
+        let (x, y) = canvas_position(&target, &event);
+        let drag = current_drag.get();
+        with_current_simulation(|simulation| {
+            *simulation.mouse_position.borrow_mut() = cursor;
+            let Some((action, (last_x, last_y))) = drag else {
+                return;
+            };
+            let mut camera = simulation.camera.borrow_mut();
+            if action == DragAction::Pan {
+                camera.pan(x - last_x, y - last_y);
+            } else if let (Some(from), Some(to)) = (camera.world_position(last_x, last_y), camera.world_position(x, y)) {
+                simulation.brush_strokes.borrow_mut().push(BrushStroke {
+                    from,
+                    to,
+                    erase: action == DragAction::Erase,
+                });
+            }
+        });
+        if let Some((action, _)) = drag {
+            current_drag.set(Some((action, (x, y))));
+        }
+    }) as Box<dyn FnMut(_)>);
+    canvas.add_event_listener_with_callback("mousemove", mousemove.as_ref().unchecked_ref())?;
+    mousemove.forget();
+
+    let mouseup = Closure::wrap(Box::new(move |_: MouseEvent| drag.set(None)) as Box<dyn FnMut(_)>);
+    for event in ["mouseup", "mouseleave"] {
+        canvas.add_event_listener_with_callback(event, mouseup.as_ref().unchecked_ref())?;
+    }
+    mouseup.forget();
+
+    // the right button erases, so keep the context menu out of the way
+    let contextmenu = Closure::wrap(Box::new(move |event: MouseEvent| event.prevent_default()) as Box<dyn FnMut(_)>);
+    canvas.add_event_listener_with_callback("contextmenu", contextmenu.as_ref().unchecked_ref())?;
+    contextmenu.forget();
+    Ok(())
+}
+
+// the program that steps the world with `source`, the assembled fragment shader `name`
+fn link_rule_program(
+    context: &WebGl2RenderingContext,
+    vert_shader: &WebGlShader,
+    name: &str,
+    source: &str,
+) -> Result<WebGlProgram, GlError> {
+    let frag_shader = compile_shader(context, WebGl2RenderingContext::FRAGMENT_SHADER, name, source)?;
+    let program = link_program(context, name, vert_shader, &frag_shader);
+    context.delete_shader(Some(&frag_shader));
+    let program = program?;
+    if let Err(error) = check_uniforms(context, &program, name, &STATE_UNIFORMS) {
+        context.delete_program(Some(&program));
+        return Err(error);
+    }
+    Ok(program)
+}
+
+fn setup_vertex_buffer(
+    context: &WebGl2RenderingContext,
+    program: &WebGlProgram,
+    vertices: &[f32],
+) -> Result<(WebGlBuffer, WebGlVertexArrayObject), JsValue> {
+    let position_attribute_location = context.get_attrib_location(program, "position");
+    let buffer = context.create_buffer().ok_or("Failed to create buffer")?;
+    context.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
+
+    unsafe {
+        let positions_array_buf_view = js_sys::Float32Array::view(vertices);
+
+        context.buffer_data_with_array_buffer_view(
+            WebGl2RenderingContext::ARRAY_BUFFER,
+            &positions_array_buf_view,
+            WebGl2RenderingContext::STATIC_DRAW,
+        );
+    }
+
+    let vao = context
+        .create_vertex_array()
+        .ok_or("Could not create vertex array object")?;
+    context.bind_vertex_array(Some(&vao));
+
+    context.vertex_attrib_pointer_with_i32(
+        position_attribute_location as u32,
+        2,
+        WebGl2RenderingContext::FLOAT,
+        false,
+        0,
+        0,
+    );
+    context.enable_vertex_attrib_array(position_attribute_location as u32);
+
+    context.bind_vertex_array(Some(&vao));
+
+    Ok((buffer, vao))
+}
+
+// `name` is the module the source was assembled from, for the error
+pub fn compile_shader(
+    context: &WebGl2RenderingContext,
+    shader_type: u32,
+    name: &str,
+    source: &str,
+) -> Result<WebGlShader, GlError> {
+    // creating objects only fails once the context is gone
+    let shader = context.create_shader(shader_type).ok_or(GlError::ContextLost)?;
+    context.shader_source(&shader, source);
+    context.compile_shader(&shader);
+
+    if context
+        .get_shader_parameter(&shader, WebGl2RenderingContext::COMPILE_STATUS)
+        .as_bool()
+        .unwrap_or(false)
+    {
+        Ok(shader)
+    } else {
+        let log = context.get_shader_info_log(&shader).unwrap_or_default();
+        context.delete_shader(Some(&shader));
+        Err(GlError::compile(name, source, &log))
+    }
+}
+
+// `name` is the module of the fragment shader, for the error
+pub fn link_program(
+    context: &WebGl2RenderingContext,
+    name: &str,
+    vert_shader: &WebGlShader,
+    frag_shader: &WebGlShader,
+) -> Result<WebGlProgram, GlError> {
+    let program = context.create_program().ok_or(GlError::ContextLost)?;
+
+    context.attach_shader(&program, vert_shader);
+    context.attach_shader(&program, frag_shader);
+    context.link_program(&program);
+
+    if context
+        .get_program_parameter(&program, WebGl2RenderingContext::LINK_STATUS)
+        .as_bool()
+        .unwrap_or(false)
+    {
+        Ok(program)
+    } else {
+        let log = context.get_program_info_log(&program).unwrap_or_default();
+        context.delete_program(Some(&program));
+        Err(GlError::Link {
+            shader: name.to_string(),
+            log,
+        })
+    }
+}
+
+// uniforms every rule shader has, which the simulation cannot run without
+const STATE_UNIFORMS: [&str; 2] = ["u_current_state", "u_resolution"];
+
+fn check_uniforms(context: &WebGl2RenderingContext, program: &WebGlProgram, shader: &str, names: &[&str]) -> Result<(), GlError> {
+    match names.iter().find(|name| context.get_uniform_location(program, name).is_none()) {
+        Some(name) => Err(GlError::MissingUniform {
+            shader: shader.to_string(),
+            name: name.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn upload_state(
+    context: &WebGl2RenderingContext,
+    texture: &WebGlTexture,
+    grid: &Grid,
+    precision: StatePrecision,
+) -> Result<(), JsValue> {
+    let width = grid.width() as i32;
+    let height = grid.height() as i32;
+
+    context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+    match precision {
+        StatePrecision::Float32 => unsafe {
+            let initial_state = grid.to_rgba32f();
+            let initial_state = js_sys::Float32Array::view(&initial_state);
+            context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_array_buffer_view(
+                WebGl2RenderingContext::TEXTURE_2D,
+                0,
+                precision.internal_format(),
+                width,
+                height,
+                0,
+                WebGl2RenderingContext::RGBA,
+                precision.data_type(),
+                Some(&initial_state),
+            )?;
+        },
+        StatePrecision::Byte => {
+            context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+                WebGl2RenderingContext::TEXTURE_2D,
+                0,
+                precision.internal_format(),
+                width,
+                height,
+                0,
+                WebGl2RenderingContext::RGBA,
+                precision.data_type(),
+                Some(&grid.to_rgba8()),
+            )?;
+        }
+    }
+
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_WRAP_S,
+        WebGl2RenderingContext::CLAMP_TO_EDGE as i32,
+    );
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_WRAP_T,
+        WebGl2RenderingContext::CLAMP_TO_EDGE as i32,
+    );
+
+    Ok(())
+}
+
+// writes `grid` into the state texture with its bottom-left cell at (x, y)
+fn upload_state_region(
+    context: &WebGl2RenderingContext,
+    texture: &WebGlTexture,
+    x: i32,
+    y: i32,
+    grid: &Grid,
+    precision: StatePrecision,
+) -> Result<(), JsValue> {
+    let width = grid.width() as i32;
+    let height = grid.height() as i32;
+
+    context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+    match precision {
+        StatePrecision::Float32 => unsafe {
+            let cells = grid.to_rgba32f();
+            let cells = js_sys::Float32Array::view(&cells);
+            context.tex_sub_image_2d_with_i32_and_i32_and_u32_and_type_and_opt_array_buffer_view(
+                WebGl2RenderingContext::TEXTURE_2D,
+                0,
+                x,
+                y,
+                width,
+                height,
+                WebGl2RenderingContext::RGBA,
+                precision.data_type(),
+                Some(&cells),
+            )
+        },
+        StatePrecision::Byte => context.tex_sub_image_2d_with_i32_and_i32_and_u32_and_type_and_opt_u8_array(
+            WebGl2RenderingContext::TEXTURE_2D,
+            0,
+            x,
+            y,
+            width,
+            height,
+            WebGl2RenderingContext::RGBA,
+            precision.data_type(),
+            Some(&grid.to_rgba8()),
+        ),
+    }
+}
+
+// R32F texture holding the kernel weights, read with texelFetch in shaders/rules/lenia.glsl
+fn upload_kernel(
+    context: &WebGl2RenderingContext,
+    texture: &WebGlTexture,
+    kernel: &Kernel,
+) -> Result<(), JsValue> {
+    context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+    unsafe {
+        let weights = js_sys::Float32Array::view(&kernel.weights);
+        context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_array_buffer_view(
+            WebGl2RenderingContext::TEXTURE_2D,
+            0,
+            WebGl2RenderingContext::R32F as i32,
+            kernel.size,
+            kernel.size,
+            0,
+            WebGl2RenderingContext::RED,
+            WebGl2RenderingContext::FLOAT,
+            Some(&weights),
+        )?;
+    }
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+    Ok(())
+}
+
+// PALETTE_SIZE x 1 RGBA texture, indexed by state
+fn upload_palette(context: &WebGl2RenderingContext, texture: &WebGlTexture, palette: &Palette) -> Result<(), JsValue> {
+    context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+    context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+        WebGl2RenderingContext::TEXTURE_2D,
+        0,
+        WebGl2RenderingContext::RGBA as i32,
+        PALETTE_SIZE as i32,
+        1,
+        0,
+        WebGl2RenderingContext::RGBA,
+        WebGl2RenderingContext::UNSIGNED_BYTE,
+        Some(&palette.to_rgba8()),
+    )?;
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+    Ok(())
+}
+
+// R8 texture, birth table in row 0 and survival table in row 1, indexed by neighbour count
+fn upload_transition_table(
+    context: &WebGl2RenderingContext,
+    texture: &WebGlTexture,
+    birth: &[bool],
+    survival: &[bool],
+) -> Result<(), JsValue> {
+    // one texel per neighbour count, so large LtL radii outgrow it
+    let max_size = context
+        .get_parameter(WebGl2RenderingContext::MAX_TEXTURE_SIZE)?
+        .as_f64()
+        .unwrap_or(2048.0) as usize;
+    if birth.len() > max_size {
+        return Err(format!(
+            "A neighbourhood of {} cells needs a rule texture wider than the limit of {}",
+            birth.len() - 1,
+            max_size
+        )
+        .into());
+    }
+    let table: Vec<u8> = birth.iter().chain(survival).map(|&live| if live { 255 } else { 0 }).collect();
+    context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+    // rows are not padded to 4 bytes
+    context.pixel_storei(WebGl2RenderingContext::UNPACK_ALIGNMENT, 1);
+    context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+        WebGl2RenderingContext::TEXTURE_2D,
+        0,
+        WebGl2RenderingContext::R8 as i32,
+        birth.len() as i32,
+        2,
+        0,
+        WebGl2RenderingContext::RED,
+        WebGl2RenderingContext::UNSIGNED_BYTE,
+        Some(&table),
+    )?;
+    context.pixel_storei(WebGl2RenderingContext::UNPACK_ALIGNMENT, 4);
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+    Ok(())
+}
+
+fn create_framebuffer(
+    context: &WebGl2RenderingContext,
+    width: i32,
+    height: i32,
+    precision: StatePrecision,
+) -> Result<(WebGlFramebuffer, WebGlTexture), JsValue> {
+    let framebuffer = context
+        .create_framebuffer()
+        .ok_or("Failed to create framebuffer")?;
+    context.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, Some(&framebuffer));
+
+    let texture = context.create_texture().ok_or("Failed to create texture")?;
+    context.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&texture));
+    context.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+        WebGl2RenderingContext::TEXTURE_2D,
+        0,
+        precision.internal_format(),
+        width,
+        height,
+        0,
+        WebGl2RenderingContext::RGBA,
+        precision.data_type(),
+        None,
+    )?;
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+    context.tex_parameteri(
+        WebGl2RenderingContext::TEXTURE_2D,
+        WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+        WebGl2RenderingContext::NEAREST as i32,
+    );
+
+    context.framebuffer_texture_2d(
+        WebGl2RenderingContext::FRAMEBUFFER,
+        WebGl2RenderingContext::COLOR_ATTACHMENT0,
+        WebGl2RenderingContext::TEXTURE_2D,
+        Some(&texture),
+        0,
+    );
+
+    let status = context.check_framebuffer_status(WebGl2RenderingContext::FRAMEBUFFER);
+    if status != WebGl2RenderingContext::FRAMEBUFFER_COMPLETE {
+        return Err(GlError::FramebufferIncomplete { status }.into());
+    }
+
+    Ok((framebuffer, texture))
+}
+
+// cursor as fractions of the canvas, y up like the textures
+fn canvas_position(canvas: &HtmlCanvasElement, event: &MouseEvent) -> (f64, f64) {
+    let rect = canvas.get_bounding_client_rect();
+    (
+        (event.client_x() as f64 - rect.left()) / rect.width(),
+        1.0 - (event.client_y() as f64 - rect.top()) / rect.height(),
+    )
+}
+
+fn request_animation_frame(f: &Closure<dyn FnMut(f64)>) {
+    web_sys::window()
+        .unwrap()
+        .request_animation_frame(f.as_ref().unchecked_ref())
+        .expect("should register `requestAnimationFrame` OK");
+}
+
+// a fresh seed for runs that are not given one
+fn random_seed() -> u32 {
+    (random() * (u32::MAX as f64 + 1.0)) as u32
+}
+
+fn check_gl_error(context: &WebGl2RenderingContext, location: &str) -> Result<(), GlError> {
+    match context.get_error() {
+        WebGl2RenderingContext::NO_ERROR => Ok(()),
+        WebGl2RenderingContext::CONTEXT_LOST_WEBGL => Err(GlError::ContextLost),
+        code => Err(GlError::Call {
+            code,
+            location: location.to_string(),
+        }),
+    }
+}
+
+// GlErrors reach JS as {kind, message, ...the fields of the variant}, e.g. a failed compile as
+// {kind: "compile", message, shader, log, lines: [{severity, line, message, source}]}
+impl From<GlError> for JsValue {
+    fn from(error: GlError) -> Self {
+        let mut object = serde_json::json!({
+            "kind": error.kind(),
+            "message": error.to_string(),
+        });
+        let fields = match &error {
+            GlError::Compile { shader, log, lines } => serde_json::json!({
+                "shader": shader,
+                "log": log,
+                "lines": lines
+                    .iter()
+                    .map(|line| serde_json::json!({
+                        "severity": line.severity,
+                        "line": line.line,
+                        "message": line.message,
+                        "source": line.source,
+                    }))
+                    .collect::<Vec<_>>(),
+            }),
+            GlError::Link { shader, log } => serde_json::json!({ "shader": shader, "log": log }),
+            GlError::FramebufferIncomplete { status } => serde_json::json!({ "status": status }),
+            GlError::MissingUniform { shader, name } => serde_json::json!({ "shader": shader, "name": name }),
+            GlError::ContextLost => serde_json::json!({}),
+            GlError::ExtensionMissing { name } => serde_json::json!({ "name": name }),
+            GlError::Call { code, location } => serde_json::json!({ "code": code, "location": location }),
+        };
+        if let (Some(object), serde_json::Value::Object(fields)) = (object.as_object_mut(), fields) {
+            object.extend(fields);
+        }
+        js_sys::JSON::parse(&object.to_string()).unwrap_or_else(|_| JsValue::from_str(&error.to_string()))
+    }
+}
+
+// `rule` is the id of a preset (see presets) or a rulestring ("B36/S23", "B2/S/C3" or
+// "R5,C0,S34-58,B34-45,NM"), which picks the shader; `states` only matters for Primordia.
+// The world starts the way the preset does, from noise for rulestrings, unless
+// set_initial_condition chose a generator, with `density` live cells (0.5 if not given) and
+// `seed`, a fresh one if not given; the seed function returns it to replay the run.
+#[wasm_bindgen]
+pub fn reset_simulation(
+    width: i32,
+    height: i32,
+    states: i32,
+    rule: &str,
+    seed: Option<u32>,
+    density: Option<f64>,
+) -> Result<(), JsValue> {
+    let density = density.unwrap_or(DEFAULT_DENSITY);
+    if !(0.0..=1.0).contains(&density) {
+        return Err(format!("Density {} outside 0..1", density).into());
+    }
+    let (rule, start) = match Preset::find(rule) {
+        Some(preset) => (preset.rule(states)?, preset.start),
+        None => (Rule::parse(rule)?, Generator::Noise),
+    };
+    let document = web_sys::window().unwrap().document().unwrap();
+    let canvas = document.get_element_by_id("canvas").unwrap();
+    // the canvas covers the window, the world is scaled to fit it
+    let canvas: HtmlCanvasElement = canvas.dyn_into::<web_sys::HtmlCanvasElement>().unwrap();
+    canvas.set_width(document.body().unwrap().client_width() as u32);
+    canvas.set_height(document.body().unwrap().client_height() as u32);
+
+    let seeding = Seeding {
+        generator: GENERATOR.with(Cell::get).unwrap_or(start),
+        seed: seed.unwrap_or_else(random_seed),
+        density,
+    };
+    // the canvas listeners registered by start move on to the new simulation
+    let new_simulation = Simulation::new(canvas, width, height, states, seeding, rule)?;
+
+    SIMULATION.with(|simulation| {
+        let old_simulation = simulation.borrow_mut().replace(Rc::new(RefCell::new(new_simulation)));
+        if let Some(old_simulation) = old_simulation {
+            old_simulation.borrow().destroy();
+        }
+    });
+
+    Ok(())
+}
+fn load_pattern(pattern: &[Vec<f64>], x: Option<i32>, y: Option<i32>) -> Result<(), JsValue> {
+    with_simulation(|simulation| {
+        let mut grid = Grid::new(simulation.width as usize, simulation.height as usize);
+        match (x, y) {
+            (Some(x), Some(y)) => patterns::place(&mut grid, pattern, x, y),
+            _ => patterns::place_centered(&mut grid, pattern),
+        }
+        simulation.load_grid(&grid)
+    })
+}
+
+// Replaces the world with an empty one holding the RLE pattern, centered unless an offset
+// (top-left of the pattern, in cells from the top-left of the canvas) is given, and switches
+// to the rule of the RLE header, if there is one. Returns that rule.
+#[wasm_bindgen]
+pub fn load_rle(rle: &str, x: Option<i32>, y: Option<i32>) -> Result<Option<String>, JsValue> {
+    let pattern = RlePattern::parse(rle)?;
+    if let Some(rule) = &pattern.rule {
+        let rule = Rule::parse(rule)?;
+        with_simulation(|simulation| simulation.set_rule(rule))?;
+    }
+    let states = with_simulation(|simulation| Ok(simulation.rule.rle_states()))?;
+    load_pattern(&pattern.to_pattern(states), x, y)?;
+    Ok(pattern.rule)
+}
+
+// Same as load_rle for the cells of a Lenia animal, also switching to its kernel and growth
+// parameters; returns the animal's name
+#[wasm_bindgen]
+pub fn load_lenia_animal(json: &str, x: Option<i32>, y: Option<i32>) -> Result<String, JsValue> {
+    let animal = Animal::from_json(json)?;
+    with_simulation(|simulation| simulation.set_rule(Rule::Lenia(animal.params)))?;
+    load_pattern(&animal.cells, x, y)?;
+    Ok(animal.name)
+}
+
+// Replaces the Lenia kernel and growth function of the running simulation, keeping the world.
+// `core` is one of polynomial, exponential, step, staircase or gaussian, `growth` one of
+// polynomial, gaussian (the default) or step.
+#[wasm_bindgen]
+pub fn set_lenia_kernel(
+    radius: i32,
+    peaks: Vec<f32>,
+    core: &str,
+    mu: f32,
+    sigma: f32,
+    time_step: f32,
+    growth: Option<String>,
+) -> Result<(), JsValue> {
+    if !(1..=MAX_RADIUS).contains(&radius) || peaks.is_empty() {
+        return Err(format!("Kernel needs a radius of 1 to {} and one or more peaks", MAX_RADIUS).into());
+    }
+    let core = KernelCore::from_name(core).ok_or_else(|| format!("Unknown kernel core {}", core))?;
+    let growth = match growth.as_deref() {
+        None => GrowthFunction::Gaussian,
+        Some(name) => GrowthFunction::from_name(name).ok_or_else(|| format!("Unknown growth function {}", name))?,
+    };
+    let params = LeniaParams::new(Kernel::generate(radius, &peaks, core), time_step, mu, sigma, growth)?;
+    with_simulation(|simulation| simulation.set_rule(Rule::Lenia(params)))
+}
+
+// Changes one rule parameter of the running simulation without reseeding it, e.g. b1 or s2
+// for Larger than Life and Primordia, T, m or s for Lenia. Takes effect on the next frame.
+#[wasm_bindgen]
+pub fn set_param(name: &str, value: f32) -> Result<(), JsValue> {
+    with_simulation(|simulation| {
+        let mut rule = simulation.rule.clone();
+        rule.set_param(name, value)?;
+        simulation.set_rule(rule)
+    })
+}
+
+fn with_simulation<T>(f: impl FnOnce(&mut Simulation) -> Result<T, JsValue>) -> Result<T, JsValue> {
+    SIMULATION.with(|simulation| {
+        let simulation = simulation.borrow();
+        let mut simulation = simulation.as_ref().ok_or("Simulation not started")?.borrow_mut();
+        if simulation.context.is_context_lost() {
+            return Err(GlError::ContextLost.into());
+        }
+        f(&mut simulation)
+    })
+}
+
+// Stops the animation loop from advancing; the last frame stays on the canvas
+#[wasm_bindgen]
+pub fn pause() {
+    PAUSED.with(|paused| paused.set(true));
+}
+
+#[wasm_bindgen]
+pub fn resume() {
+    PAUSED.with(|paused| paused.set(false));
+}
+
+#[wasm_bindgen]
+pub fn is_paused() -> bool {
+    PAUSED.with(Cell::get)
+}
+
+// Advances exactly n generations, paused or not
+#[wasm_bindgen]
+pub fn step(n: u32) -> Result<(), JsValue> {
+    with_simulation(|simulation| {
+        for _ in 0..n {
+            simulation.step_generation();
+        }
+        simulation.present();
+        Ok(())
+    })
+}
+
+// Palette of the display pass: grayscale, viridis, inferno, fire, amber, gold or position,
+// or "default" for the one of the current rule. The state texture is not affected.
+#[wasm_bindgen]
+pub fn set_palette(name: &str) -> Result<(), JsValue> {
+    let colour_map = match name {
+        "default" => None,
+        name => Some(ColourMap::from_name(name).ok_or_else(|| format!("Unknown palette {}", name))?),
+    };
+    COLOUR_MAP.with(|current| *current.borrow_mut() = colour_map);
+    Ok(())
+}
+
+// Custom gradient palette, e.g. "0:#000000, 0.5:#ff8000, 1:#ffffff"
+#[wasm_bindgen]
+pub fn set_palette_stops(stops: &str) -> Result<(), JsValue> {
+    let palette = Palette::parse(stops)?;
+    COLOUR_MAP.with(|current| *current.borrow_mut() = Some(ColourMap::Palette(palette)));
+    Ok(())
+}
+
+// Shows the world at zoom times its size, centred on cell (x, y) counted from the top-left
+#[wasm_bindgen]
+pub fn set_camera(zoom: f64, x: f64, y: f64) -> Result<(), JsValue> {
+    if !(zoom.is_finite() && zoom > 0.0) {
+        return Err(format!("Invalid zoom {}", zoom).into());
+    }
+    with_simulation(|simulation| {
+        let width = simulation.width as f64;
+        let height = simulation.height as f64;
+        let mut camera = simulation.camera.borrow_mut();
+        camera.zoom = zoom.clamp(Camera::MIN_ZOOM, Camera::MAX_ZOOM);
+        camera.center = ((x / width).rem_euclid(1.0), (1.0 - y / height).rem_euclid(1.0));
+        Ok(())
+    })
+}
+
+#[wasm_bindgen]
+pub fn reset_camera() -> Result<(), JsValue> {
+    with_simulation(|simulation| {
+        let mut camera = simulation.camera.borrow_mut();
+        *camera = Camera {
+            extent: camera.extent,
+            ..Camera::default()
+        };
+        Ok(())
+    })
+}
+
+// Generator of the worlds reset_simulation starts: noise, square, disk, smooth, soups, c2, c4,
+// d8, gradient_x, gradient_y or radial, or "default" for the one of the preset. `size` is the
+// fraction of the world the square or disk covers, or the size in cells of the smooth noise
+// features, soup patches or symmetric soup.
+#[wasm_bindgen]
+pub fn set_initial_condition(name: &str, size: Option<f64>) -> Result<(), JsValue> {
+    let generator = match name {
+        "default" => None,
+        name => Some(Generator::from_name(name, size)?),
+    };
+    GENERATOR.with(|current| current.set(generator));
+    Ok(())
+}
+
+// The rule presets reset_simulation takes, as objects with their id, name, shader (the module
+// under shaders/ that runs the rule), world size and rulestring, if the rule has one
+#[wasm_bindgen]
+pub fn presets() -> Result<JsValue, JsValue> {
+    let presets: Vec<_> = Preset::all()
+        .into_iter()
+        .map(|preset| {
+            serde_json::json!({
+                "id": preset.id,
+                "name": preset.name,
+                "shader": shaders::rule_module(&preset.rule),
+                "width": preset.world.0,
+                "height": preset.world.1,
+                "rulestring": preset.rule.rulestring(),
+            })
+        })
+        .collect();
+    js_sys::JSON::parse(&serde_json::Value::from(presets).to_string())
+}
+
+// The complete fragment shader that runs `rule`, a preset id or a rulestring, as the
+// simulation compiles it
+#[wasm_bindgen]
+pub fn shader_source(rule: &str) -> Result<String, JsValue> {
+    let rule = match Preset::find(rule) {
+        Some(preset) => preset.rule,
+        None => Rule::parse(rule)?,
+    };
+    Ok(shaders::rule_shader(&rule)?)
+}
+
+// Steps the running world with `source`, a fragment shader written like those under
+// shaders/rules (it may #include their modules) and given the defines and uniforms of the
+// current rule, until the rule or one of its parameters changes. A shader that fails to
+// compile or link, or lacks u_current_state or u_resolution, is reported as a GlError whose
+// lines point into `source` after includes, as custom_shader_source returns it; the running
+// shader is kept then.
+#[wasm_bindgen]
+pub fn set_custom_shader(source: &str) -> Result<(), JsValue> {
+    with_simulation(|simulation| {
+        let source = shaders::assemble_source(CUSTOM_SHADER, source, &shaders::rule_defines(&simulation.rule))?;
+        Ok(simulation.set_fragment_source(CUSTOM_SHADER, source)?)
+    })
+}
+
+// `source` with its includes expanded and the defines of the current rule, as
+// set_custom_shader compiles it
+#[wasm_bindgen]
+pub fn custom_shader_source(source: &str) -> Result<String, JsValue> {
+    with_simulation(|simulation| {
+        Ok(shaders::assemble_source(CUSTOM_SHADER, source, &shaders::rule_defines(&simulation.rule))?)
+    })
+}
+
+// seed of the initial world of the current run, to pass back to reset_simulation
+#[wasm_bindgen]
+pub fn seed() -> Result<u32, JsValue> {
+    with_simulation(|simulation| Ok(simulation.seeding.seed))
+}
+
+#[wasm_bindgen]
+pub fn density() -> Result<f64, JsValue> {
+    with_simulation(|simulation| Ok(simulation.seeding.density))
+}
+
+// Selects the pattern a left click stamps, by name (see patterns::LIBRARY); None or "" goes
+// back to painting
+#[wasm_bindgen]
+pub fn select_stamp(name: Option<String>) -> Result<(), JsValue> {
+    let pattern = match name.as_deref() {
+        None | Some("") => None,
+        Some(name) => Some(patterns::from_name(name).ok_or_else(|| format!("Unknown pattern {}", name))?),
+    };
+    STAMP.with(|stamp| *stamp.borrow_mut() = pattern);
+    Ok(())
+}
+
+// Same as select_stamp for an RLE pattern; returns the rule from its header, if any
+#[wasm_bindgen]
+pub fn select_stamp_rle(rle: &str) -> Result<Option<String>, JsValue> {
+    let pattern = RlePattern::parse(rle)?;
+    // the stamp goes into the running world, so its states are read as that rule's
+    let states = with_simulation(|simulation| Ok(simulation.rule.rle_states()))?;
+    STAMP.with(|stamp| *stamp.borrow_mut() = Some(pattern.to_pattern(states)));
+    Ok(pattern.rule)
+}
+
+// Turns the stamp clockwise by quarter turns, after mirroring it left to right if asked
+#[wasm_bindgen]
+pub fn set_stamp_orientation(quarter_turns: i32, mirrored: bool) {
+    STAMP_ORIENTATION.with(|orientation| orientation.set(Orientation { quarter_turns, mirrored }));
+}
+
+// Brush of the mouse: radius in cells, shape "circle", "square" or "soft", and intensity, the
+// state a continuous rule gains (painting) or loses (erasing) under the brush centre per stamp
+#[wasm_bindgen]
+pub fn set_brush(radius: f64, shape: &str, intensity: f32) -> Result<(), JsValue> {
+    if !(radius.is_finite() && radius >= 0.5) {
+        return Err(format!("Invalid brush radius {}", radius).into());
+    }
+    if !(intensity > 0.0 && intensity <= 1.0) {
+        return Err(format!("Brush intensity {} outside 0..1", intensity).into());
+    }
+    let shape = match shape {
+        "circle" => BrushShape::Circle,
+        "square" => BrushShape::Square,
+        "soft" => BrushShape::Soft,
+        _ => return Err(format!("Unknown brush shape {}", shape).into()),
+    };
+    BRUSH.with(|brush| brush.set(Brush { radius, shape, intensity }));
+    Ok(())
+}
+
+// What a window resize does: "letterbox" keeps the world and scales it to fit, "resize" keeps
+// the cell size and grows or crops the world around its centre
+#[wasm_bindgen]
+pub fn set_resize_mode(mode: &str) -> Result<(), JsValue> {
+    let mode = match mode {
+        "letterbox" => ResizeMode::Letterbox,
+        "resize" => ResizeMode::Resize,
+        _ => return Err(format!("Unknown resize mode {}", mode).into()),
+    };
+    RESIZE_MODE.with(|current| current.set(mode));
+    Ok(())
+}
+
+// Changes the world size, keeping the cells around the centre
+#[wasm_bindgen]
+pub fn resize_world(width: i32, height: i32) -> Result<(), JsValue> {
+    with_simulation(|simulation| simulation.resize_world(width, height))
+}
+
+// "float32" when the state textures are floating point, "uint8" when the context could only
+// give 8-bit ones
+#[wasm_bindgen]
+pub fn state_precision() -> Result<String, JsValue> {
+    with_simulation(|simulation| Ok(simulation.precision.name().to_string()))
+}
+
+// Target simulation speed, independent of the display refresh rate, at most
+// MAX_GENERATIONS_PER_SECOND; generations_per_second returns the rate that was set
+#[wasm_bindgen]
+pub fn set_generations_per_second(generations_per_second: f64) -> Result<(), JsValue> {
+    if !(generations_per_second.is_finite() && generations_per_second > 0.0) {
+        return Err(format!("Invalid generations per second {}", generations_per_second).into());
+    }
+    GENERATIONS_PER_SECOND.with(|rate| rate.set(generations_per_second.min(MAX_GENERATIONS_PER_SECOND)));
+    Ok(())
+}
+
+#[wasm_bindgen]
+pub fn generations_per_second() -> f64 {
+    GENERATIONS_PER_SECOND.with(Cell::get)
+}
+
+fn read_current_grid() -> Result<Grid, JsValue> {
+    with_simulation(|simulation| simulation.read_grid())
+}
+
+// Current world as RLE, every non-zero cell counting as alive, or with the dying states of a
+// Generations rule as Golly's multi-state tags
+#[wasm_bindgen]
+pub fn export_rle(rule: Option<String>) -> Result<String, JsValue> {
+    let (rulestring, states) = with_simulation(|simulation| {
+        Ok((simulation.rule.rulestring(), simulation.rule.rle_states()))
+    })?;
+    let rule = rule.or(rulestring);
+    Ok(read_current_grid()?.to_rle(rule.as_deref(), states))
+}
+
+// Whether the running rule is binary (Life-like, Generations or Larger than Life), whose
+// worlds export_rle writes; the others are exported with export_png
+#[wasm_bindgen]
+pub fn is_binary() -> Result<bool, JsValue> {
+    with_simulation(|simulation| Ok(simulation.rule.is_binary()))
+}
+
+// Current world as a grayscale PNG, for the continuous rules
+#[wasm_bindgen]
+pub fn export_png() -> Result<Vec<u8>, JsValue> {
+    Ok(read_current_grid()?.to_png())
+}
+
+// One byte per cell, bottom row first
+#[wasm_bindgen]
+pub fn export_bytes() -> Result<Vec<u8>, JsValue> {
+    Ok(read_current_grid()?.to_bytes())
+}
+
+// One float per cell in 0..1, bottom row first
+#[wasm_bindgen]
+pub fn export_cells() -> Result<Vec<f32>, JsValue> {
+    Ok(read_current_grid()?.cells().to_vec())
+}
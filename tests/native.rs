@@ -0,0 +1,95 @@
+// The simulation core used as a plain Rust library, without the web feature or a WebGL
+// context: run with `cargo test --no-default-features`.
+
+use gol_webgl::cpu::{Engine, Grid};
+use gol_webgl::generators::{Generator, Seeding};
+use gol_webgl::patterns;
+use gol_webgl::presets::Preset;
+use gol_webgl::rle::RlePattern;
+use gol_webgl::rules::Rule;
+
+fn run(rule: Rule, grid: Grid, generations: u64) -> Grid {
+    let mut engine = Engine::new(rule, grid);
+    engine.run(generations);
+    engine.grid().clone()
+}
+
+#[test]
+fn seeded_runs_replay() {
+    let preset = Preset::find("gol").unwrap();
+    let seeding = Seeding {
+        generator: Generator::Noise,
+        seed: 42,
+        density: 0.3,
+    };
+    let first = run(preset.rule.clone(), seeding.grid(64, 64, false), 20);
+    let second = run(preset.rule, seeding.grid(64, 64, false), 20);
+    assert_eq!(first, second);
+    assert!(first.population() > 0);
+}
+
+#[test]
+fn rle_export_loads_back() {
+    let mut grid = Grid::new(40, 30);
+    patterns::place_centered(&mut grid, &patterns::from_name("gosper_glider_gun").unwrap());
+    let grid = run(Rule::parse("B3/S23").unwrap(), grid, 30);
+
+    let pattern = RlePattern::parse(&grid.to_rle(Some("B3/S23"), 2)).unwrap();
+    assert_eq!((pattern.width, pattern.height), (40, 30));
+    assert_eq!(pattern.rule.as_deref(), Some("B3/S23"));
+    let mut loaded = Grid::new(40, 30);
+    patterns::place(&mut loaded, &pattern.to_pattern(2), 0, 0);
+    assert_eq!(loaded, grid);
+}
+
+#[test]
+fn generations_rle_keeps_the_dying_states() {
+    // enough states for the two-letter tags past X
+    for rulestring in ["B2/S/C3", "B2/S345/C30"] {
+        let rule = Rule::parse(rulestring).unwrap();
+        let seeding = Seeding {
+            generator: Generator::Noise,
+            seed: 7,
+            density: 0.3,
+        };
+        let grid = run(rule.clone(), seeding.grid(48, 32, false), 40);
+
+        let rle = grid.to_rle(rule.rulestring().as_deref(), rule.rle_states());
+        let pattern = RlePattern::parse(&rle).unwrap();
+        assert_eq!(pattern.rule, rule.rulestring());
+        let mut loaded = Grid::new(48, 32);
+        patterns::place(&mut loaded, &pattern.to_pattern(rule.rle_states()), 0, 0);
+        assert_eq!(loaded, grid, "{}", rulestring);
+    }
+}
+
+#[test]
+fn ltl_rle_loads_back_with_its_rule() {
+    let rule = Rule::larger_than_life();
+    let seeding = Seeding {
+        generator: Generator::Noise,
+        seed: 11,
+        density: 0.5,
+    };
+    let grid = run(rule.clone(), seeding.grid(64, 48, false), 10);
+
+    let pattern = RlePattern::parse(&grid.to_rle(rule.rulestring().as_deref(), rule.rle_states())).unwrap();
+    assert_eq!(Rule::parse(pattern.rule.as_deref().unwrap()), Ok(rule.clone()));
+    let mut loaded = Grid::new(64, 48);
+    patterns::place(&mut loaded, &pattern.to_pattern(rule.rle_states()), 0, 0);
+    assert_eq!(loaded, grid);
+    assert!(grid.population() > 0);
+}
+
+#[test]
+fn png_export_has_one_scanline_per_row() {
+    let seeding = Seeding {
+        generator: Preset::find("orbium").unwrap().start,
+        seed: 1,
+        density: 0.5,
+    };
+    let png = seeding.grid(64, 48, true).to_png();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    // width and height of the IHDR chunk
+    assert_eq!(&png[16..24], &[0, 0, 0, 64, 0, 0, 0, 48]);
+}